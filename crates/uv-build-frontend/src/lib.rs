@@ -301,11 +301,7 @@ impl SourceBuild {
             uv_virtualenv::create_venv(
                 temp_dir.path(),
                 interpreter.clone(),
-                uv_virtualenv::Prompt::None,
-                false,
-                false,
-                false,
-                false,
+                uv_virtualenv::VenvOptions::default(),
             )?
         };
 
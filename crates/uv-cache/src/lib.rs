@@ -1,10 +1,12 @@
 use std::cmp::max;
+use std::ffi::OsStr;
 use std::fmt::{Display, Formatter};
 use std::io;
 use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use rustc_hash::FxHashSet;
 use tracing::debug;
@@ -12,7 +14,7 @@ use tracing::debug;
 pub use archive::ArchiveId;
 use uv_cache_info::Timestamp;
 use uv_distribution_types::InstalledDist;
-use uv_fs::{cachedir, directories};
+use uv_fs::{cachedir, directories, LockedFile, Simplified};
 use uv_normalize::PackageName;
 use uv_pypi_types::ResolutionMetadata;
 
@@ -188,6 +190,45 @@ impl Cache {
         self.bucket(CacheBucket::Archive).join(id)
     }
 
+    /// Return the path to an archive in the cache, recording that the archive is in use.
+    ///
+    /// See [`Cache::touch_archive`].
+    pub fn access_archive(&self, id: &ArchiveId) -> PathBuf {
+        let path = self.archive(id);
+        self.touch_archive(&path);
+        path
+    }
+
+    /// Record that the archive at the given path is in use. Paths outside the archive bucket are
+    /// ignored.
+    ///
+    /// File system access times are unreliable (e.g., on file systems mounted with `noatime`), so
+    /// uses are recorded explicitly, as the modification time of a marker file, to inform
+    /// [`Cache::prune_to_size`].
+    pub fn touch_archive(&self, path: &Path) {
+        let Some(id) = path.file_name() else {
+            return;
+        };
+        if path.parent().and_then(Path::file_name)
+            != Some(OsStr::new(CacheBucket::Archive.to_str()))
+        {
+            return;
+        }
+        let marker = self.bucket(CacheBucket::Archive).join(ACCESSED).join(id);
+        if let Err(err) = touch(&marker) {
+            debug!(
+                "Failed to record archive access at {}: {err}",
+                marker.display()
+            );
+        }
+    }
+
+    /// Acquire an exclusive lock on the cache, to prevent concurrent garbage collection (e.g.,
+    /// [`Cache::prune`] and [`Cache::prune_to_size`]).
+    pub fn lock(&self) -> Result<LockedFile, io::Error> {
+        LockedFile::acquire_blocking(self.root.join(".lock"), self.root.user_display())
+    }
+
     /// Create a temporary directory to be used as a Python virtual environment.
     pub fn venv_dir(&self) -> io::Result<tempfile::TempDir> {
         fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
@@ -258,6 +299,7 @@ impl Cache {
         let archive_entry = self.entry(CacheBucket::Archive, "", &id);
         fs_err::create_dir_all(archive_entry.dir())?;
         uv_fs::rename_with_retry(temp_dir.as_ref(), archive_entry.path()).await?;
+        self.touch_archive(archive_entry.path());
 
         // Create a symlink to the directory store.
         fs_err::create_dir_all(path.as_ref().parent().expect("Cache entry to have parent"))?;
@@ -407,6 +449,7 @@ impl Cache {
             if entry.file_name() == "CACHEDIR.TAG"
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
+                || entry.file_name() == ".lock"
             {
                 continue;
             }
@@ -509,6 +552,9 @@ impl Cache {
             Ok(entries) => {
                 for entry in entries {
                     let entry = entry?;
+                    if entry.file_name() == ACCESSED {
+                        continue;
+                    }
                     let path = fs_err::canonicalize(entry.path())?;
                     if !references.contains(&path) {
                         debug!("Removing dangling cache archive: {}", path.display());
@@ -520,10 +566,137 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
+        // Fifth, remove the access records of any archives that no longer exist.
+        match fs_err::read_dir(self.bucket(CacheBucket::Archive).join(ACCESSED)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    if !self
+                        .bucket(CacheBucket::Archive)
+                        .join(entry.file_name())
+                        .exists()
+                    {
+                        summary += rm_rf(entry.path())?;
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
+        Ok(summary)
+    }
+
+    /// Evict unzipped archives until they fit within the given size budget, in bytes.
+    ///
+    /// Archives (i.e., unzipped wheels and cached environments) account for the bulk of the cache,
+    /// and are the only entries that can be evicted without losing information that's expensive
+    /// to recompute, so the budget applies to them alone. They're evicted in least-recently-used
+    /// order, as recorded by [`Cache::access_archive`]; archives without a record are ordered by
+    /// their creation. Metadata and HTTP cache policies are retained, but any entries that pointed
+    /// to an evicted archive are removed, so the archive will be re-downloaded (or rebuilt) on
+    /// next use.
+    pub fn prune_to_size(&self, budget: u64) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+
+        // Collect the archives, along with their last use and size.
+        let archives_dir = self.bucket(CacheBucket::Archive);
+        let mut archives = Vec::new();
+        match fs_err::read_dir(&archives_dir) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    if entry.file_name() == ACCESSED || !entry.file_type()?.is_dir() {
+                        continue;
+                    }
+                    let marker = archives_dir.join(ACCESSED).join(entry.file_name());
+                    let accessed = match fs_err::metadata(&marker) {
+                        Ok(metadata) => metadata.modified()?,
+                        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                            entry.metadata()?.modified()?
+                        }
+                        Err(err) => return Err(err),
+                    };
+                    let size = dir_size(&entry.path())?;
+                    archives.push((accessed, size, entry.path(), marker));
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+            Err(err) => return Err(err),
+        }
+
+        let mut total = archives.iter().map(|(_, size, ..)| size).sum::<u64>();
+        if total <= budget {
+            return Ok(summary);
+        }
+
+        // Evict the least-recently-used archives until we're within budget.
+        archives.sort_by_key(|(accessed, ..)| *accessed);
+        for (_, size, path, marker) in archives {
+            if total <= budget {
+                break;
+            }
+            debug!("Evicting cache archive: {}", path.display());
+            summary += rm_rf(path)?;
+            summary += rm_rf(marker)?;
+            total -= size;
+        }
+
+        // Remove any links to the evicted archives.
+        for bucket in CacheBucket::iter() {
+            let bucket = self.bucket(bucket);
+            if bucket.is_dir() {
+                for entry in walkdir::WalkDir::new(bucket) {
+                    let entry = entry?;
+                    if entry.file_type().is_symlink() && !entry.path().exists() {
+                        debug!("Removing dangling cache link: {}", entry.path().display());
+                        summary += rm_rf(entry.path())?;
+                    }
+                }
+            }
+        }
+
         Ok(summary)
     }
 }
 
+/// The directory, within the archive bucket, that records when each archive was last used.
+const ACCESSED: &str = ".accessed";
+
+/// Create the given file, or update its modification time if it already exists.
+fn touch(path: &Path) -> Result<(), io::Error> {
+    let file = match fs_err::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+    {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            fs_err::create_dir_all(path.parent().expect("Marker to have parent"))?;
+            fs_err::OpenOptions::new()
+                .create(true)
+                .truncate(false)
+                .write(true)
+                .open(path)?
+        }
+        Err(err) => return Err(err),
+    };
+    file.file().set_modified(SystemTime::now())
+}
+
+/// Return the total size of the files in a directory, in bytes, without following symlinks.
+fn dir_size(path: &Path) -> Result<u64, io::Error> {
+    let mut size = 0;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            size += entry.metadata()?.len();
+        }
+    }
+    Ok(size)
+}
+
 pub trait CleanReporter: Send + Sync {
     /// Called after one file or directory is removed.
     fn on_clean(&self);
@@ -784,7 +957,7 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v6",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v3",
+            Self::Interpreter => "interpreter-v4",
             // Note that when bumping this, you'll also need to bump it
             // in crates/uv/tests/cache_clean.rs.
             Self::Simple => "simple-v14",
@@ -1155,3 +1328,71 @@ impl Refresh {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+
+    /// Create an archive of the given size, linked from the wheel bucket, last used at the given
+    /// number of seconds after the epoch.
+    fn archive(cache: &Cache, name: &str, size: usize, accessed: u64) -> PathBuf {
+        let id = ArchiveId::new();
+        let path = cache.access_archive(&id);
+        fs_err::create_dir_all(&path).unwrap();
+        fs_err::write(path.join("data"), vec![0; size]).unwrap();
+
+        let marker = cache.bucket(CacheBucket::Archive).join(ACCESSED).join(&id);
+        fs_err::OpenOptions::new()
+            .write(true)
+            .open(marker)
+            .unwrap()
+            .file()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(accessed))
+            .unwrap();
+
+        let link = cache.bucket(CacheBucket::Wheels).join(name);
+        fs_err::create_dir_all(link.parent().unwrap()).unwrap();
+        uv_fs::replace_symlink(&path, &link).unwrap();
+        link
+    }
+
+    #[test]
+    fn prune_to_size() {
+        let cache = Cache::temp().unwrap().init().unwrap();
+        let first = archive(&cache, "first", 100, 3);
+        let second = archive(&cache, "second", 100, 1);
+        let third = archive(&cache, "third", 100, 2);
+
+        // The archives fit within the budget.
+        let removal = cache.prune_to_size(300).unwrap();
+        assert_eq!(removal.num_dirs, 0);
+        assert!(first.exists() && second.exists() && third.exists());
+
+        // The least-recently-used archive is evicted, along with its link.
+        cache.prune_to_size(250).unwrap();
+        assert!(first.exists() && third.exists());
+        assert!(!second.exists() && !second.is_symlink());
+
+        // Using an archive protects it from eviction.
+        cache.touch_archive(&fs_err::canonicalize(&third).unwrap());
+        cache.prune_to_size(150).unwrap();
+        assert!(!first.exists() && !first.is_symlink());
+        assert!(third.exists());
+    }
+
+    #[test]
+    fn touch_archive() {
+        let cache = Cache::temp().unwrap().init().unwrap();
+        let accessed = cache.bucket(CacheBucket::Archive).join(ACCESSED);
+
+        // Paths outside the archive bucket are ignored.
+        cache.touch_archive(&cache.bucket(CacheBucket::Wheels).join("foo"));
+        assert!(!accessed.exists());
+
+        let id = ArchiveId::new();
+        cache.touch_archive(&cache.archive(&id));
+        assert!(accessed.join(&id).is_file());
+    }
+}
@@ -546,6 +546,15 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Evict the least-recently-used unzipped wheels until they fit within the given size.
+    ///
+    /// Unzipped wheels (and cached environments) account for the bulk of the cache. Evicted wheels
+    /// are re-downloaded or rebuilt on next use, while metadata and HTTP cache entries are retained.
+    ///
+    /// Accepts a number of bytes, optionally followed by a binary unit (e.g., `512MiB` or `10G`).
+    #[arg(long, value_parser = parse_size)]
+    pub max_size: Option<u64>,
 }
 
 #[derive(Args)]
@@ -910,6 +919,32 @@ fn parse_maybe_file_path(input: &str) -> Result<Maybe<PathBuf>, String> {
     }
 }
 
+/// Parse a size in bytes, with an optional binary unit suffix (e.g., `512MiB` or `10G`).
+fn parse_size(input: &str) -> Result<u64, String> {
+    let index = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(index);
+    let number = number
+        .parse::<u64>()
+        .map_err(|err| format!("invalid size `{input}`: {err}"))?;
+    let multiplier: u64 = match unit.trim_start() {
+        "" | "B" => 1,
+        "K" | "KiB" => 1 << 10,
+        "M" | "MiB" => 1 << 20,
+        "G" | "GiB" => 1 << 30,
+        "T" | "TiB" => 1 << 40,
+        unit => {
+            return Err(format!(
+                "invalid unit `{unit}` (expected one of: `B`, `KiB`, `MiB`, `GiB`, or `TiB`)"
+            ))
+        }
+    };
+    number
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("size `{input}` is too large"))
+}
+
 // Parse a string, mapping the empty string to `None`.
 #[allow(clippy::unnecessary_wraps)]
 fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
@@ -2362,6 +2397,13 @@ pub struct VenvArgs {
     #[arg(long)]
     pub relocatable: bool,
 
+    /// Don't write activation scripts into the virtual environment.
+    ///
+    /// Useful in CI and container images, where only the interpreter and `site-packages` are
+    /// needed.
+    #[arg(long)]
+    pub no_activators: bool,
+
     #[command(flatten)]
     pub index_args: IndexArgs,
 
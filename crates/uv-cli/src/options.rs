@@ -291,12 +291,14 @@ pub fn resolver_options(
         } else {
             prerelease
         },
+        prerelease_package: None,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
         no_build_isolation: flag(no_build_isolation, build_isolation),
         no_build_isolation_package: Some(no_build_isolation_package),
         exclude_newer,
+        exclude_newer_package: None,
         link_mode,
         no_build: flag(no_build, build),
         no_build_package: Some(no_build_package),
@@ -392,6 +394,7 @@ pub fn resolver_installer_options(
         } else {
             prerelease
         },
+        prerelease_package: None,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -402,6 +405,7 @@ pub fn resolver_installer_options(
             Some(no_build_isolation_package)
         },
         exclude_newer,
+        exclude_newer_package: None,
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
         no_build: flag(no_build, build),
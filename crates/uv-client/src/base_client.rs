@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, Response};
+use reqwest::{Client, ClientBuilder, NoProxy, Proxy, Response};
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
@@ -7,6 +7,7 @@ use reqwest_retry::{
 };
 use std::error::Error;
 use std::fmt::Debug;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
@@ -23,7 +24,10 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
+use crate::middleware::{
+    retry_after, HostConcurrencyMiddleware, HostRateLimitMiddleware, OfflineMiddleware,
+    RetryAfterMiddleware,
+};
 use crate::tls::read_identity;
 use crate::Connectivity;
 
@@ -55,6 +59,13 @@ pub struct BaseClientBuilder<'a> {
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
     default_timeout: Duration,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    http2_prior_knowledge: bool,
+    http2_adaptive_window: bool,
+    max_concurrent_requests_per_host: Option<NonZeroUsize>,
+    max_requests_per_second_per_host: Option<u32>,
+    proxies: Vec<Proxy>,
     extra_middleware: Option<ExtraMiddleware>,
 }
 
@@ -89,6 +100,13 @@ impl BaseClientBuilder<'_> {
             platform: None,
             auth_integration: AuthIntegration::default(),
             default_timeout: Duration::from_secs(30),
+            connect_timeout: None,
+            request_timeout: None,
+            http2_prior_knowledge: false,
+            http2_adaptive_window: false,
+            max_concurrent_requests_per_host: None,
+            max_requests_per_second_per_host: None,
+            proxies: vec![],
             extra_middleware: None,
         }
     }
@@ -155,6 +173,67 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the timeout for establishing a connection, separate from the read timeout.
+    #[must_use]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Set a deadline for each request as a whole, from connecting until the end of the response
+    /// body.
+    #[must_use]
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = Some(request_timeout);
+        self
+    }
+
+    /// Assume that all hosts speak HTTP/2, skipping ALPN negotiation.
+    ///
+    /// By default, HTTP/2 is used whenever the server advertises it during the TLS handshake.
+    #[must_use]
+    pub fn http2_prior_knowledge(mut self, http2_prior_knowledge: bool) -> Self {
+        self.http2_prior_knowledge = http2_prior_knowledge;
+        self
+    }
+
+    /// Use adaptive flow control for HTTP/2 connections, growing the window size to match the
+    /// bandwidth-delay product.
+    #[must_use]
+    pub fn http2_adaptive_window(mut self, http2_adaptive_window: bool) -> Self {
+        self.http2_adaptive_window = http2_adaptive_window;
+        self
+    }
+
+    /// Limit the number of simultaneous requests sent to any single host.
+    ///
+    /// A request counts towards the limit until its response body has been read or dropped.
+    /// The overall number of concurrent downloads is bounded separately, by the caller's
+    /// [`uv_configuration::Concurrency`].
+    #[must_use]
+    pub fn max_concurrent_requests_per_host(mut self, limit: NonZeroUsize) -> Self {
+        self.max_concurrent_requests_per_host = Some(limit);
+        self
+    }
+
+    /// Limit the rate of requests sent to any single host, allowing short bursts.
+    #[must_use]
+    pub fn max_requests_per_second_per_host(mut self, limit: u32) -> Self {
+        self.max_requests_per_second_per_host = Some(limit);
+        self
+    }
+
+    /// Route requests through the given proxies.
+    ///
+    /// Explicit proxies take precedence over those configured via the `HTTP_PROXY`, `HTTPS_PROXY`,
+    /// and `ALL_PROXY` environment variables, which continue to apply to any requests the explicit
+    /// proxies don't intercept. `NO_PROXY` only applies to the proxies read from the environment.
+    #[must_use]
+    pub fn proxies(mut self, proxies: Vec<Proxy>) -> Self {
+        self.proxies = proxies;
+        self
+    }
+
     #[must_use]
     pub fn extra_middleware(mut self, middleware: ExtraMiddleware) -> Self {
         self.extra_middleware = Some(middleware);
@@ -276,6 +355,26 @@ impl<'a> BaseClientBuilder<'a> {
             .read_timeout(timeout)
             .tls_built_in_root_certs(false);
 
+        // Apply the connect and total request timeouts, if configured.
+        let client_builder = if let Some(connect_timeout) = self.connect_timeout {
+            client_builder.connect_timeout(connect_timeout)
+        } else {
+            client_builder
+        };
+        let client_builder = if let Some(request_timeout) = self.request_timeout {
+            client_builder.timeout(request_timeout)
+        } else {
+            client_builder
+        };
+
+        // Configure HTTP/2.
+        let client_builder = if self.http2_prior_knowledge {
+            client_builder.http2_prior_knowledge()
+        } else {
+            client_builder
+        };
+        let client_builder = client_builder.http2_adaptive_window(self.http2_adaptive_window);
+
         // If necessary, accept invalid certificates.
         let client_builder = match security {
             Security::Secure => client_builder,
@@ -288,6 +387,18 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
+        // Configure any explicit proxies. Since `reqwest` ignores the proxy environment variables
+        // once a proxy is set explicitly, add those back afterwards, at a lower precedence.
+        let client_builder = if self.proxies.is_empty() {
+            client_builder
+        } else {
+            self.proxies
+                .iter()
+                .cloned()
+                .chain(environment_proxies())
+                .fold(client_builder, ClientBuilder::proxy)
+        };
+
         // Configure mTLS.
         let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
             match read_identity(&ssl_client_cert) {
@@ -313,6 +424,9 @@ impl<'a> BaseClientBuilder<'a> {
 
                 // Avoid uncloneable errors with a streaming body during publish.
                 if self.retries > 0 {
+                    // Honor `Retry-After` on rate-limited and unavailable responses.
+                    client = client.with(RetryAfterMiddleware::new(self.retries));
+
                     // Initialize the retry strategy.
                     let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
                         self.retry_policy(),
@@ -321,6 +435,18 @@ impl<'a> BaseClientBuilder<'a> {
                     client = client.with(retry_strategy);
                 }
 
+                // Limit the number of in-flight requests per host. Since middleware added later
+                // runs closer to the network, this applies to every retry, and no slot is held
+                // while waiting to retry.
+                if let Some(limit) = self.max_concurrent_requests_per_host {
+                    client = client.with(HostConcurrencyMiddleware::new(limit));
+                }
+
+                // Rate-limit requests per host, again counting every retry.
+                if let Some(limit) = self.max_requests_per_second_per_host {
+                    client = client.with(HostRateLimitMiddleware::new(limit));
+                }
+
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
@@ -433,6 +559,14 @@ impl RetryableStrategy for UvRetryableStrategy {
             {
                 Some(Retryable::Transient)
             }
+            // Responses with a `Retry-After` header are retried by the `RetryAfterMiddleware`.
+            Some(Retryable::Transient)
+                if res
+                    .as_ref()
+                    .is_ok_and(|response| retry_after(response).is_some()) =>
+            {
+                None
+            }
             default => default,
         };
 
@@ -497,3 +631,34 @@ fn find_source<E: Error + 'static>(orig: &dyn Error) -> Option<&E> {
     }
     None
 }
+
+/// Read the proxies configured via the `HTTP_PROXY`, `HTTPS_PROXY`, and `ALL_PROXY` environment
+/// variables (or their lowercase variants), excluding the hosts listed in `NO_PROXY`.
+fn environment_proxies() -> Vec<Proxy> {
+    let no_proxy = NoProxy::from_env();
+    [
+        EnvVars::HTTP_PROXY,
+        EnvVars::HTTPS_PROXY,
+        EnvVars::ALL_PROXY,
+    ]
+    .into_iter()
+    .filter_map(|name| {
+        let url = env::var(name)
+            .or_else(|_| env::var(name.to_ascii_lowercase()))
+            .ok()
+            .filter(|url| !url.is_empty())?;
+        let proxy = match name {
+            EnvVars::HTTP_PROXY => Proxy::http(&url),
+            EnvVars::HTTPS_PROXY => Proxy::https(&url),
+            _ => Proxy::all(&url),
+        };
+        match proxy {
+            Ok(proxy) => Some(proxy.no_proxy(no_proxy.clone())),
+            Err(err) => {
+                debug!("Ignoring invalid proxy in `{name}`: {err}");
+                None
+            }
+        }
+    })
+    .collect()
+}
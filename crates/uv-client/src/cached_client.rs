@@ -165,6 +165,9 @@ pub enum CacheControl {
     MustRevalidate,
     /// Allow the client to return stale responses.
     AllowStale,
+    /// Override the freshness lifetime from the `cache-control` header of the response: treat
+    /// cached responses younger than the given duration as fresh, and older ones as stale.
+    MaxAge(Duration),
 }
 
 impl From<Freshness> for CacheControl {
@@ -459,10 +462,16 @@ impl CachedClient {
         cache_control: CacheControl,
         cached: DataWithCachePolicy,
     ) -> Result<CachedResponse, Error> {
+        let within_max_age = match cache_control {
+            CacheControl::MaxAge(max_age) => cached.cache_policy.is_within_max_age(max_age),
+            _ => false,
+        };
+
         // Apply the cache control header, if necessary.
         match cache_control {
             CacheControl::None | CacheControl::AllowStale => {}
-            CacheControl::MustRevalidate => {
+            CacheControl::MaxAge(_) if within_max_age => {}
+            CacheControl::MustRevalidate | CacheControl::MaxAge(_) => {
                 req.headers_mut().insert(
                     http::header::CACHE_CONTROL,
                     http::HeaderValue::from_static("no-cache"),
@@ -475,7 +484,14 @@ impl CachedClient {
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
-                CacheControl::None | CacheControl::MustRevalidate => {
+                CacheControl::MaxAge(_) if within_max_age => {
+                    debug!(
+                        "Found stale response within the configured max-age for: {}",
+                        req.url()
+                    );
+                    CachedResponse::FreshCache(cached)
+                }
+                CacheControl::None | CacheControl::MustRevalidate | CacheControl::MaxAge(_) => {
                     debug!("Found stale response for: {}", req.url());
                     self.send_cached_handle_stale(req, cached, new_cache_policy_builder)
                         .await?
@@ -3,6 +3,7 @@ use std::ops::Deref;
 
 use async_http_range_reader::AsyncHttpRangeReaderError;
 use async_zip::error::ZipError;
+use owo_colors::OwoColorize;
 use url::Url;
 
 use uv_distribution_filename::{WheelFilename, WheelFilenameError};
@@ -43,6 +44,17 @@ impl Error {
         matches!(&*self.kind, ErrorKind::Offline(_))
     }
 
+    /// Returns `true` if the server timed out, refused the connection, or returned a server error,
+    /// such that the same request may succeed against a mirror.
+    pub(crate) fn is_server_unavailable(&self) -> bool {
+        let ErrorKind::WrappedReqwestError(_, err) = &*self.kind else {
+            return false;
+        };
+        err.is_timeout()
+            || err.is_connect()
+            || err.status().is_some_and(|status| status.is_server_error())
+    }
+
     /// Returns `true` if this error corresponds to an I/O "not found" error.
     pub(crate) fn is_file_not_exists(&self) -> bool {
         let ErrorKind::Io(ref err) = &*self.kind else {
@@ -163,6 +175,16 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the registry")]
     PackageNotFound(String),
 
+    /// An index returned an unexpected response while fetching a package.
+    #[error("Failed to fetch `{package}` from {index}{}", index_hint(source))]
+    Index {
+        package: PackageName,
+        /// The redacted index URL, along with its name, if any.
+        index: String,
+        #[source]
+        source: Box<Error>,
+    },
+
     /// The package was not found in the local (file-based) index.
     #[error("Package `{0}` was not found in the local index")]
     FileNotFound(String),
@@ -235,7 +257,47 @@ pub enum ErrorKind {
     Offline(String),
 }
 
+/// Return a hint for an error returned by an index, or an empty string if there's nothing to add.
+fn index_hint(err: &Error) -> String {
+    match err.kind() {
+        ErrorKind::BadHtml { .. }
+        | ErrorKind::BadJson { .. }
+        | ErrorKind::MissingContentType(_)
+        | ErrorKind::UnsupportedMediaType(..) => format!(
+            "\n\n{}{} the index did not return a valid Simple API page; index URLs typically end in `/simple`",
+            "hint".bold().cyan(),
+            ":".bold(),
+        ),
+        ErrorKind::WrappedReqwestError(_, err)
+            if err
+                .status()
+                .is_some_and(|status| status.is_server_error()) =>
+        {
+            format!(
+                "\n\n{}{} the index returned a server error, which is often temporary; try again later",
+                "hint".bold().cyan(),
+                ":".bold(),
+            )
+        }
+        _ => String::new(),
+    }
+}
+
 impl ErrorKind {
+    /// Returns `true` if the error stems from a failed request to an index, or an invalid response
+    /// from it, as opposed to, e.g., a failure to read or write the cache.
+    pub(crate) fn is_index_response(&self) -> bool {
+        matches!(
+            self,
+            Self::WrappedReqwestError(..)
+                | Self::BadJson { .. }
+                | Self::BadHtml { .. }
+                | Self::MissingContentType(_)
+                | Self::InvalidContentTypeHeader(..)
+                | Self::UnsupportedMediaType(..)
+        )
+    }
+
     pub(crate) fn from_reqwest(url: Url, error: reqwest::Error) -> Self {
         Self::WrappedReqwestError(url, WrappedReqwestError::from(error))
     }
@@ -731,6 +731,16 @@ impl ArchivedCachePolicy {
             || self.response.headers.cc.s_maxage_seconds.is_some()
     }
 
+    /// Returns true if the age of the response doesn't exceed the given
+    /// maximum age.
+    ///
+    /// Unlike the freshness check in `before_request`, this ignores the
+    /// freshness lifetime set by the origin server, such that callers can
+    /// override it in either direction.
+    pub fn is_within_max_age(&self, max_age: Duration) -> bool {
+        self.age(SystemTime::now()) <= max_age
+    }
+
     /// Returns true if the response is considered fresh as per [RFC 9111
     /// S4.2]. If the response is not fresh, then it considered stale and ought
     /// to be revalidated with the origin server.
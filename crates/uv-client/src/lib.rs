@@ -9,6 +9,7 @@ pub use registry_client::{
     Connectivity, RegistryClient, RegistryClientBuilder, SimpleMetadata, SimpleMetadatum,
     VersionFiles,
 };
+pub use resumable::{range_validator, request_range, resumable_stream, RangeResponse};
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
 
 mod base_client;
@@ -21,5 +22,6 @@ mod linehaul;
 mod middleware;
 mod registry_client;
 mod remote_metadata;
+mod resumable;
 mod rkyvutil;
 mod tls;
@@ -1,8 +1,15 @@
-use http::Extensions;
+use futures::StreamExt;
+use http::{Extensions, StatusCode};
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 
-use reqwest::{Request, Response};
+use reqwest::{Body, Request, Response, ResponseBuilderExt};
 use reqwest_middleware::{Middleware, Next};
+use tokio::sync::Semaphore;
+use tracing::debug;
 use url::Url;
 
 /// A custom error type for the offline middleware.
@@ -45,3 +52,309 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// The longest `Retry-After` delay we're willing to wait for before giving up.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// A middleware that retries `429 Too Many Requests` and `503 Service Unavailable` responses
+/// after the delay requested by the server in the `Retry-After` header.
+///
+/// Responses without a (valid) `Retry-After` header are left to the transient retry middleware,
+/// which applies jittered exponential backoff instead.
+pub(crate) struct RetryAfterMiddleware {
+    retries: u32,
+}
+
+impl RetryAfterMiddleware {
+    pub(crate) fn new(retries: u32) -> Self {
+        Self { retries }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryAfterMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut n_past_retries = 0;
+        loop {
+            // Requests with a streaming body can't be replayed.
+            let Some(fresh_req) = req.try_clone() else {
+                return next.run(req, extensions).await;
+            };
+            let response = next.clone().run(fresh_req, extensions).await?;
+            if n_past_retries >= self.retries {
+                return Ok(response);
+            }
+            let Some(delay) = retry_after(&response) else {
+                return Ok(response);
+            };
+            debug!(
+                "Received {} from {}; retrying after {}s",
+                response.status(),
+                response.url(),
+                delay.as_secs()
+            );
+            // Release the response (and any per-host slot it holds) while waiting.
+            drop(response);
+            tokio::time::sleep(delay).await;
+            n_past_retries += 1;
+        }
+    }
+}
+
+/// Return the delay requested by the server via the `Retry-After` header, if the response is a
+/// `429` or `503` and the delay is within [`MAX_RETRY_AFTER`].
+///
+/// The header may contain either a number of seconds or an HTTP date, as per
+/// [RFC 9110 S10.2.3](https://www.rfc-editor.org/rfc/rfc9110.html#section-10.2.3).
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    if !matches!(
+        response.status(),
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    ) {
+        return None;
+    }
+    let value = response
+        .headers()
+        .get(http::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    let delay = if let Ok(seconds) = value.parse::<u64>() {
+        Duration::from_secs(seconds)
+    } else {
+        let timestamp = jiff::fmt::rfc2822::DateTimeParser::new()
+            .parse_timestamp(value)
+            .ok()?;
+        let at = SystemTime::try_from(timestamp).ok()?;
+        at.duration_since(SystemTime::now()).unwrap_or_default()
+    };
+    (delay <= MAX_RETRY_AFTER).then_some(delay)
+}
+
+/// A middleware that limits the number of in-flight requests to any single host.
+///
+/// A request holds its slot until the response body has been read to completion (or dropped),
+/// such that the limit bounds the number of open downloads, not just the requests awaiting
+/// headers.
+pub(crate) struct HostConcurrencyMiddleware {
+    limit: NonZeroUsize,
+    hosts: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl HostConcurrencyMiddleware {
+    pub(crate) fn new(limit: NonZeroUsize) -> Self {
+        Self {
+            limit,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return the [`Semaphore`] guarding requests to the given host.
+    fn semaphore(&self, host: &str) -> Arc<Semaphore> {
+        let mut hosts = self.hosts.lock().unwrap();
+        hosts
+            .entry(host.to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(self.limit.get())))
+            .clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for HostConcurrencyMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(host) = req.url().host_str() else {
+            return next.run(req, extensions).await;
+        };
+        let permit = self
+            .semaphore(host)
+            .acquire_owned()
+            .await
+            .expect("host semaphore is never closed");
+        let response = next.run(req, extensions).await?;
+
+        // Move the permit into the response body, to release it once the body is consumed or
+        // dropped. Round-tripping through `http::Response` preserves the status and headers, but
+        // not the URL, which has to be carried across explicitly.
+        let url = response.url().clone();
+        let (mut parts, body) = http::Response::<Body>::from(response).into_parts();
+        let (url_parts, ()) = http::Response::builder()
+            .url(url)
+            .body(())
+            .expect("an empty response is valid")
+            .into_parts();
+        parts.extensions.extend(url_parts.extensions);
+        let stream = Response::from(http::Response::new(body))
+            .bytes_stream()
+            .map(move |chunk| {
+                let _permit = &permit;
+                chunk
+            });
+        Ok(Response::from(http::Response::from_parts(
+            parts,
+            Body::wrap_stream(stream),
+        )))
+    }
+}
+
+/// A token bucket tracking the requests available to a single host.
+#[derive(Debug)]
+struct TokenBucket {
+    /// The number of available tokens, which may be negative if requests are queued.
+    tokens: f64,
+    /// The last time the bucket was refilled.
+    refilled: Instant,
+}
+
+/// A middleware that limits the rate of requests sent to any single host, using a token bucket
+/// per host that allows bursts of up to one second's worth of requests.
+pub(crate) struct HostRateLimitMiddleware {
+    /// The number of requests per second allowed for each host.
+    rate: f64,
+    hosts: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl HostRateLimitMiddleware {
+    pub(crate) fn new(requests_per_second: u32) -> Self {
+        Self {
+            rate: f64::from(requests_per_second.max(1)),
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserve a token for the given host, returning how long to wait before sending the request.
+    fn reserve(&self, host: &str) -> Duration {
+        let now = Instant::now();
+        let mut hosts = self.hosts.lock().unwrap();
+        let bucket = hosts.entry(host.to_string()).or_insert(TokenBucket {
+            tokens: self.rate,
+            refilled: now,
+        });
+
+        // Refill the bucket, up to its capacity.
+        let elapsed = now.duration_since(bucket.refilled).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.rate);
+        bucket.refilled = now;
+
+        // Take a token, waiting for the deficit to be refilled if the bucket is empty.
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.rate)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for HostRateLimitMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if let Some(host) = req.url().host_str() {
+            let delay = self.reserve(host);
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+        }
+        next.run(req, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use http::StatusCode;
+    use reqwest::Response;
+
+    use super::retry_after;
+
+    fn response(status: StatusCode, retry_after: &str) -> Response {
+        Response::from(
+            http::Response::builder()
+                .status(status)
+                .header(http::header::RETRY_AFTER, retry_after)
+                .body("")
+                .unwrap(),
+        )
+    }
+
+    fn http_date(time: SystemTime) -> String {
+        jiff::Timestamp::try_from(time)
+            .unwrap()
+            .strftime("%a, %d %b %Y %H:%M:%S GMT")
+            .to_string()
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        assert_eq!(
+            retry_after(&response(StatusCode::TOO_MANY_REQUESTS, "5")),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            retry_after(&response(StatusCode::SERVICE_UNAVAILABLE, " 0 ")),
+            Some(Duration::ZERO)
+        );
+
+        // Delays beyond the maximum are left to the transient retry middleware.
+        assert_eq!(
+            retry_after(&response(StatusCode::TOO_MANY_REQUESTS, "3600")),
+            None
+        );
+
+        // Only rate-limited and unavailable responses are retried.
+        assert_eq!(
+            retry_after(&response(StatusCode::INTERNAL_SERVER_ERROR, "5")),
+            None
+        );
+        assert_eq!(
+            retry_after(&response(StatusCode::TOO_MANY_REQUESTS, "soon")),
+            None
+        );
+    }
+
+    #[test]
+    fn retry_after_http_date() {
+        let delay = retry_after(&response(
+            StatusCode::TOO_MANY_REQUESTS,
+            &http_date(SystemTime::now() + Duration::from_secs(30)),
+        ))
+        .unwrap();
+        assert!(
+            delay > Duration::from_secs(25) && delay <= Duration::from_secs(30),
+            "{delay:?}"
+        );
+
+        // Dates in the past mean "retry now".
+        assert_eq!(
+            retry_after(&response(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Sun, 06 Nov 1994 08:49:37 GMT"
+            )),
+            Some(Duration::ZERO)
+        );
+
+        // Dates beyond the maximum delay are left to the transient retry middleware.
+        assert_eq!(
+            retry_after(&response(
+                StatusCode::TOO_MANY_REQUESTS,
+                &http_date(SystemTime::now() + Duration::from_secs(3600)),
+            )),
+            None
+        );
+    }
+}
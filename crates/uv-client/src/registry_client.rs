@@ -2,17 +2,20 @@ use async_http_range_reader::AsyncHttpRangeReader;
 use futures::{FutureExt, TryStreamExt};
 use http::HeaderMap;
 use itertools::Either;
-use reqwest::{Client, Response, StatusCode};
+use reqwest::{Client, Proxy, Response, StatusCode};
 use reqwest_middleware::ClientWithMiddleware;
+use std::cell::Cell;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::str::FromStr;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tracing::{info_span, instrument, trace, warn, Instrument};
 use url::Url;
 
-use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness, WheelCache};
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{IndexStrategy, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
@@ -39,6 +42,8 @@ pub struct RegistryClientBuilder<'a> {
     index_urls: IndexUrls,
     index_strategy: IndexStrategy,
     cache: Cache,
+    index_max_age: Option<Duration>,
+    index_mirrors: BTreeMap<IndexUrl, Vec<IndexUrl>>,
     base_client_builder: BaseClientBuilder<'a>,
 }
 
@@ -48,6 +53,8 @@ impl RegistryClientBuilder<'_> {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
             cache,
+            index_max_age: None,
+            index_mirrors: BTreeMap::new(),
             base_client_builder: BaseClientBuilder::new(),
         }
     }
@@ -98,12 +105,88 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    /// Set the read timeout, unless overridden by `UV_HTTP_TIMEOUT`.
+    #[must_use]
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.base_client_builder = self.base_client_builder.default_timeout(read_timeout);
+        self
+    }
+
+    #[must_use]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.base_client_builder = self.base_client_builder.connect_timeout(connect_timeout);
+        self
+    }
+
+    #[must_use]
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.base_client_builder = self.base_client_builder.request_timeout(request_timeout);
+        self
+    }
+
+    #[must_use]
+    pub fn http2_prior_knowledge(mut self, http2_prior_knowledge: bool) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .http2_prior_knowledge(http2_prior_knowledge);
+        self
+    }
+
+    #[must_use]
+    pub fn http2_adaptive_window(mut self, http2_adaptive_window: bool) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .http2_adaptive_window(http2_adaptive_window);
+        self
+    }
+
+    #[must_use]
+    pub fn max_concurrent_requests_per_host(mut self, limit: NonZeroUsize) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .max_concurrent_requests_per_host(limit);
+        self
+    }
+
+    #[must_use]
+    pub fn max_requests_per_second_per_host(mut self, limit: u32) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .max_requests_per_second_per_host(limit);
+        self
+    }
+
+    #[must_use]
+    pub fn proxies(mut self, proxies: Vec<Proxy>) -> Self {
+        self.base_client_builder = self.base_client_builder.proxies(proxies);
+        self
+    }
+
     #[must_use]
     pub fn cache(mut self, cache: Cache) -> Self {
         self.cache = cache;
         self
     }
 
+    /// Override the freshness lifetime of cached simple-index pages, regardless of the
+    /// `cache-control` headers sent by the index: pages younger than the given duration are used
+    /// as-is, and older pages are revalidated.
+    #[must_use]
+    pub fn index_max_age(mut self, index_max_age: Duration) -> Self {
+        self.index_max_age = Some(index_max_age);
+        self
+    }
+
+    /// Register mirrors for an index.
+    ///
+    /// If the index times out or returns a server error, the request is retried against each
+    /// mirror in turn. Subsequent requests prefer whichever URL responded fastest.
+    #[must_use]
+    pub fn index_mirrors(mut self, index: IndexUrl, mirrors: Vec<IndexUrl>) -> Self {
+        self.index_mirrors.insert(index, mirrors);
+        self
+    }
+
     #[must_use]
     pub fn client(mut self, client: Client) -> Self {
         self.base_client_builder = self.base_client_builder.client(client);
@@ -144,6 +227,8 @@ impl<'a> RegistryClientBuilder<'a> {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
             cache: self.cache,
+            index_max_age: self.index_max_age,
+            index_mirrors: Arc::new(IndexMirrors::new(self.index_mirrors)),
             connectivity,
             client,
             timeout,
@@ -165,6 +250,8 @@ impl<'a> RegistryClientBuilder<'a> {
             index_urls: self.index_urls,
             index_strategy: self.index_strategy,
             cache: self.cache,
+            index_max_age: self.index_max_age,
+            index_mirrors: Arc::new(IndexMirrors::new(self.index_mirrors)),
             connectivity,
             client,
             timeout,
@@ -180,11 +267,62 @@ impl<'a> TryFrom<BaseClientBuilder<'a>> for RegistryClientBuilder<'a> {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
             cache: Cache::temp()?,
+            index_max_age: None,
+            index_mirrors: BTreeMap::new(),
             base_client_builder: value,
         })
     }
 }
 
+/// The mirrors configured for each index, along with the observed latency of each URL.
+#[derive(Debug, Default)]
+struct IndexMirrors {
+    mirrors: BTreeMap<IndexUrl, Vec<IndexUrl>>,
+    /// The latency of the last network response from each URL, or `None` if the last request
+    /// failed. Responses served from the cache aren't timed.
+    latency: Mutex<BTreeMap<IndexUrl, Option<Duration>>>,
+}
+
+impl IndexMirrors {
+    fn new(mirrors: BTreeMap<IndexUrl, Vec<IndexUrl>>) -> Self {
+        Self {
+            mirrors,
+            latency: Mutex::default(),
+        }
+    }
+
+    /// Return the URLs to try for the given index, in order of preference, or `None` if the index
+    /// has no mirrors.
+    ///
+    /// URLs are ordered by their last observed latency; URLs that haven't been tried yet come next,
+    /// in the configured order, followed by URLs whose last request failed.
+    fn candidates(&self, index: &IndexUrl) -> Option<Vec<IndexUrl>> {
+        let mirrors = self.mirrors.get(index)?;
+        let latency = self.latency.lock().unwrap();
+        let mut candidates = std::iter::once(index)
+            .chain(mirrors)
+            .cloned()
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|url| match latency.get(url) {
+            Some(Some(duration)) => (0, *duration),
+            None => (1, Duration::ZERO),
+            Some(None) => (2, Duration::ZERO),
+        });
+        Some(candidates)
+    }
+
+    fn record_success(&self, url: &IndexUrl, duration: Duration) {
+        self.latency
+            .lock()
+            .unwrap()
+            .insert(url.clone(), Some(duration));
+    }
+
+    fn record_failure(&self, url: &IndexUrl) {
+        self.latency.lock().unwrap().insert(url.clone(), None);
+    }
+}
+
 /// A client for fetching packages from a `PyPI`-compatible index.
 #[derive(Debug, Clone)]
 pub struct RegistryClient {
@@ -196,6 +334,10 @@ pub struct RegistryClient {
     client: CachedClient,
     /// Used for the remote wheel METADATA cache.
     cache: Cache,
+    /// The age up to which a cached simple-index page is used without revalidation.
+    index_max_age: Option<Duration>,
+    /// The mirrors to fall back to when an index is unavailable.
+    index_mirrors: Arc<IndexMirrors>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
@@ -248,7 +390,7 @@ impl RegistryClient {
 
         let mut results = Vec::new();
         for index in it {
-            match self.simple_single_index(package_name, index).await {
+            match self.simple_with_mirrors(package_name, index).await {
                 Ok(metadata) => {
                     results.push((index, metadata));
 
@@ -267,7 +409,13 @@ impl RegistryClient {
                         Some(StatusCode::FORBIDDEN) => {
                             capabilities.set_forbidden(index.clone());
                         }
-                        _ => return Err(ErrorKind::WrappedReqwestError(url, err).into()),
+                        _ => {
+                            return Err(self.index_error(
+                                package_name,
+                                index,
+                                ErrorKind::WrappedReqwestError(url, err).into(),
+                            ))
+                        }
                     },
 
                     // The package is unavailable due to a lack of connectivity.
@@ -276,6 +424,11 @@ impl RegistryClient {
                     // The package could not be found in the local index.
                     ErrorKind::FileNotFound(_) => {}
 
+                    // Attach the index to any errors in its responses.
+                    other if other.is_index_response() => {
+                        return Err(self.index_error(package_name, index, other.into()))
+                    }
+
                     other => return Err(other.into()),
                 },
             };
@@ -293,14 +446,73 @@ impl RegistryClient {
         Ok(results)
     }
 
+    /// Attach the index (and its name, if any) to an error encountered while querying it.
+    fn index_error(&self, package_name: &PackageName, index: &IndexUrl, err: Error) -> Error {
+        let index = if let Some(name) = self.index_urls.name(index) {
+            format!("index `{name}` ({})", index.redacted())
+        } else {
+            format!("index {}", index.redacted())
+        };
+        ErrorKind::Index {
+            package: package_name.clone(),
+            index,
+            source: Box::new(err),
+        }
+        .into()
+    }
+
+    /// Fetch the [`SimpleMetadata`] from a single index for a given package, falling back to any
+    /// configured mirrors if the index is unavailable.
+    async fn simple_with_mirrors(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let Some(candidates) = self.index_mirrors.candidates(index) else {
+            return self
+                .simple_single_index(package_name, index, &Cell::default())
+                .await;
+        };
+
+        let mut candidates = candidates.into_iter().peekable();
+        while let Some(candidate) = candidates.next() {
+            let start = Instant::now();
+            let responded = Cell::default();
+            match self
+                .simple_single_index(package_name, &candidate, &responded)
+                .await
+            {
+                Ok(metadata) => {
+                    // Only rank the URL if it actually responded over the network, rather than
+                    // being served from the cache.
+                    if let Some(responded) = responded.get() {
+                        self.index_mirrors
+                            .record_success(&candidate, responded.duration_since(start));
+                    }
+                    return Ok(metadata);
+                }
+                Err(err) if err.is_server_unavailable() && candidates.peek().is_some() => {
+                    warn!("Index {candidate} is unavailable, trying the next mirror: {err}");
+                    self.index_mirrors.record_failure(&candidate);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        unreachable!("an index always has at least one candidate URL")
+    }
+
     /// Fetch the [`SimpleMetadata`] from a single index for a given package.
     ///
     /// The index can either be a PEP 503-compatible remote repository, or a local directory laid
     /// out in the same format.
+    ///
+    /// If a new response is received from a remote index, `responded` is set to the time at which
+    /// it arrived.
     async fn simple_single_index(
         &self,
         package_name: &PackageName,
         index: &IndexUrl,
+        responded: &Cell<Option<Instant>>,
     ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
         // Format the URL for PyPI.
         let mut url: Url = index.clone().into();
@@ -320,18 +532,24 @@ impl RegistryClient {
             format!("{package_name}.rkyv"),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => CacheControl::from(
-                self.cache
+            Connectivity::Online => {
+                let freshness = self
+                    .cache
                     .freshness(&cache_entry, Some(package_name))
-                    .map_err(ErrorKind::Io)?,
-            ),
+                    .map_err(ErrorKind::Io)?;
+                match (freshness, self.index_max_age) {
+                    // An explicit refresh always takes precedence over the configured max-age.
+                    (Freshness::Stale, _) | (_, None) => CacheControl::from(freshness),
+                    (_, Some(max_age)) => CacheControl::MaxAge(max_age),
+                }
+            }
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
         if matches!(index, IndexUrl::Path(_)) {
             self.fetch_local_index(package_name, &url).await
         } else {
-            self.fetch_remote_index(package_name, &url, &cache_entry, cache_control)
+            self.fetch_remote_index(package_name, &url, &cache_entry, cache_control, responded)
                 .await
         }
     }
@@ -343,6 +561,7 @@ impl RegistryClient {
         url: &Url,
         cache_entry: &CacheEntry,
         cache_control: CacheControl,
+        responded: &Cell<Option<Instant>>,
     ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
         let simple_request = self
             .uncached_client(url)
@@ -352,6 +571,7 @@ impl RegistryClient {
             .build()
             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
         let parse_simple_response = |response: Response| {
+            responded.set(Some(Instant::now()));
             async {
                 // Use the response URL, rather than the request URL, as the base for relative URLs.
                 // This ensures that we handle redirects and other URL transformations correctly.
@@ -948,15 +1168,58 @@ impl Connectivity {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use std::str::FromStr;
+    use std::time::Duration;
 
     use url::Url;
 
+    use uv_distribution_types::IndexUrl;
     use uv_normalize::PackageName;
     use uv_pypi_types::{JoinRelativeError, SimpleJson};
 
     use crate::{html::SimpleHtml, SimpleMetadata, SimpleMetadatum};
 
+    use super::IndexMirrors;
+
+    #[test]
+    fn mirror_candidates() {
+        let index = IndexUrl::from_str("https://index.example.com/simple").unwrap();
+        let mirrors = ["a", "b", "c", "d"]
+            .iter()
+            .map(|name| IndexUrl::from_str(&format!("https://{name}.example.com/simple")).unwrap())
+            .collect::<Vec<_>>();
+        let index_mirrors = IndexMirrors::new(BTreeMap::from([(index.clone(), mirrors.clone())]));
+
+        // Without any observations, the configured order is preserved.
+        assert_eq!(
+            index_mirrors.candidates(&index).unwrap(),
+            [&index]
+                .into_iter()
+                .chain(&mirrors)
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+
+        // Responsive URLs come first, fastest first, then untried URLs, then failed URLs.
+        index_mirrors.record_failure(&index);
+        index_mirrors.record_success(&mirrors[2], Duration::from_millis(50));
+        index_mirrors.record_success(&mirrors[3], Duration::from_millis(10));
+        assert_eq!(
+            index_mirrors.candidates(&index).unwrap(),
+            [
+                mirrors[3].clone(),
+                mirrors[2].clone(),
+                mirrors[0].clone(),
+                mirrors[1].clone(),
+                index.clone(),
+            ]
+        );
+
+        // Indexes without mirrors are fetched directly.
+        assert!(index_mirrors.candidates(&mirrors[0]).is_none());
+    }
+
     #[test]
     fn ignore_failing_files() {
         // 1.7.7 has an invalid requires-python field (double comma), 1.7.8 is valid
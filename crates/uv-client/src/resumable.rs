@@ -0,0 +1,148 @@
+use futures::{Stream, StreamExt};
+use reqwest::header::{
+    HeaderValue, ACCEPT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE, ETAG, IF_RANGE, LAST_MODIFIED,
+    RANGE,
+};
+use reqwest::{Response, StatusCode};
+use reqwest_middleware::ClientWithMiddleware;
+use tracing::debug;
+use url::Url;
+
+/// The response to a request for a byte range of a resource.
+#[derive(Debug)]
+pub enum RangeResponse {
+    /// The server returned the requested range.
+    Partial(Response),
+    /// The server returned the full resource instead, e.g., because it has changed since the
+    /// validator was issued, or because it doesn't support range requests.
+    Full(Response),
+}
+
+/// Returns the validator (the strong `ETag` or, failing that, the `Last-Modified` header) with
+/// which the given response can be resumed, if it's resumable at all.
+///
+/// Byte ranges refer to the encoded content, so responses that `reqwest` decompressed on the fly
+/// can't be resumed. `reqwest` strips the `Content-Length` header from such responses, so we
+/// require it to be present.
+pub fn range_validator(response: &Response) -> Option<HeaderValue> {
+    let headers = response.headers();
+    if response.status() != StatusCode::OK || !headers.contains_key(CONTENT_LENGTH) {
+        return None;
+    }
+    headers
+        .get(ETAG)
+        .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+        .or_else(|| headers.get(LAST_MODIFIED))
+        .cloned()
+}
+
+/// Request the bytes of the resource at `url` from `offset` onwards, provided that the resource
+/// still matches the given `validator`.
+pub async fn request_range(
+    client: &ClientWithMiddleware,
+    url: &Url,
+    validator: &HeaderValue,
+    offset: u64,
+) -> Result<RangeResponse, reqwest_middleware::Error> {
+    let response = client
+        .get(url.clone())
+        // Ranges refer to the encoded content, so make sure that it's not compressed.
+        .header(ACCEPT_ENCODING, HeaderValue::from_static("identity"))
+        .header(
+            RANGE,
+            HeaderValue::from_str(&format!("bytes={offset}-"))
+                .expect("`Range` is a valid header value"),
+        )
+        .header(IF_RANGE, validator.clone())
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let is_partial = response.status() == StatusCode::PARTIAL_CONTENT
+        && response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|range| range.to_str().ok())
+            .is_some_and(|range| range.starts_with(&format!("bytes {offset}-")));
+    if is_partial {
+        Ok(RangeResponse::Partial(response))
+    } else {
+        Ok(RangeResponse::Full(response))
+    }
+}
+
+/// Stream the body of `response`, resuming the download with a range request (up to `retries`
+/// times) if the connection drops partway through.
+///
+/// `offset` is the position of the start of the body within the resource, e.g., the start of the
+/// range if `response` is itself a response to a range request. `validator` is the validator of
+/// the original response, as returned by [`range_validator`]; without one, interrupted downloads
+/// can't be resumed safely, and the error is returned as-is.
+pub fn resumable_stream(
+    client: ClientWithMiddleware,
+    url: Url,
+    response: Response,
+    validator: Option<HeaderValue>,
+    offset: u64,
+    retries: u32,
+) -> impl Stream<Item = reqwest::Result<impl AsRef<[u8]>>> {
+    struct State<S> {
+        client: ClientWithMiddleware,
+        url: Url,
+        stream: Option<S>,
+        validator: Option<HeaderValue>,
+        offset: u64,
+        retries: u32,
+    }
+
+    let state = State {
+        client,
+        url,
+        stream: Some(response.bytes_stream().boxed()),
+        validator,
+        offset,
+        retries,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            let stream = state.stream.as_mut()?;
+            let err = match stream.next().await? {
+                Ok(chunk) => {
+                    state.offset += chunk.len() as u64;
+                    return Some((Ok(chunk), state));
+                }
+                Err(err) => err,
+            };
+
+            // Drop the interrupted response before issuing another request to the same host.
+            state.stream = None;
+
+            if let Some(validator) = state.validator.as_ref().filter(|_| state.retries > 0) {
+                state.retries -= 1;
+                debug!(
+                    "Download of {} interrupted after {} bytes; resuming: {err}",
+                    state.url, state.offset
+                );
+                match request_range(&state.client, &state.url, validator, state.offset).await {
+                    Ok(RangeResponse::Partial(response)) => {
+                        state.stream = Some(response.bytes_stream().boxed());
+                        continue;
+                    }
+                    Ok(RangeResponse::Full(_)) => {
+                        debug!(
+                            "Failed to resume download of {}: resource changed",
+                            state.url
+                        );
+                    }
+                    Err(resume_err) => {
+                        debug!("Failed to resume download of {}: {resume_err}", state.url);
+                    }
+                }
+            }
+
+            // Surface the original error, and end the stream.
+            return Some((Err(err), state));
+        }
+    })
+}
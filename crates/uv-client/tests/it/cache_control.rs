@@ -0,0 +1,118 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{CacheControl, RegistryClientBuilder};
+
+/// Start a server that responds to every request with the number of requests it has served so
+/// far, along with the given headers.
+async fn start_server(
+    headers: &'static [(&'static str, &'static str)],
+) -> Result<(Url, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let counter = counter.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                let count = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                let mut response = Response::builder();
+                for (name, value) in headers {
+                    response = response.header(*name, *value);
+                }
+                future::ok::<_, hyper::Error>(
+                    response
+                        .body(Full::new(Bytes::from(count.to_string())))
+                        .unwrap(),
+                )
+            });
+            tokio::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await
+                    .unwrap();
+            });
+        }
+    });
+
+    Ok((Url::parse(&format!("http://{addr}/simple/foo/"))?, requests))
+}
+
+/// Fetch the given URL through the cached client, returning the response body.
+async fn fetch(cache: &Cache, url: &Url, cache_control: CacheControl) -> Result<String> {
+    let client = RegistryClientBuilder::new(cache.clone()).build();
+    let cache_entry = cache.entry(CacheBucket::Simple, "cache-control", "foo.json");
+    let request = client
+        .cached_client()
+        .uncached()
+        .for_host(url)
+        .get(url.clone())
+        .build()?;
+    let body = client
+        .cached_client()
+        .get_serde(
+            request,
+            &cache_entry,
+            cache_control,
+            |response| async move { response.text().await },
+        )
+        .await?;
+    Ok(body)
+}
+
+/// A configured max-age keeps short-lived responses fresh.
+#[tokio::test]
+async fn max_age_extends_freshness() -> Result<()> {
+    let (url, requests) = start_server(&[("cache-control", "max-age=0")]).await?;
+    let cache = Cache::temp()?.init()?;
+
+    assert_eq!(fetch(&cache, &url, CacheControl::None).await?, "1");
+
+    // Without an override, the response is stale immediately and must be revalidated.
+    assert_eq!(fetch(&cache, &url, CacheControl::None).await?, "2");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    // With an override, the cached response is used as-is.
+    let max_age = CacheControl::MaxAge(Duration::from_secs(600));
+    assert_eq!(fetch(&cache, &url, max_age).await?, "2");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+/// A configured max-age forces revalidation of long-lived responses that are older than it.
+#[tokio::test]
+async fn max_age_shortens_freshness() -> Result<()> {
+    let (url, requests) =
+        start_server(&[("cache-control", "max-age=3600"), ("age", "120")]).await?;
+    let cache = Cache::temp()?.init()?;
+
+    assert_eq!(fetch(&cache, &url, CacheControl::None).await?, "1");
+
+    // Without an override, the response is fresh for another hour.
+    assert_eq!(fetch(&cache, &url, CacheControl::None).await?, "1");
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    // With an override, the two-minute-old response is revalidated.
+    let max_age = CacheControl::MaxAge(Duration::from_secs(60));
+    assert_eq!(fetch(&cache, &url, max_age).await?, "2");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
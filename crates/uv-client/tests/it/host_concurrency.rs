@@ -0,0 +1,88 @@
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{RegistryClient, RegistryClientBuilder};
+
+/// Start a server that responds to every request with `ok`.
+async fn start_server() -> Result<Url> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                future::ok::<_, hyper::Error>(Response::new(Full::new(Bytes::from("ok"))))
+            });
+            tokio::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await
+                    .unwrap();
+            });
+        }
+    });
+
+    Ok(Url::parse(&format!("http://{addr}/"))?)
+}
+
+async fn send(client: &RegistryClient, url: &Url) -> reqwest_middleware::Result<reqwest::Response> {
+    client
+        .cached_client()
+        .uncached()
+        .for_host(url)
+        .get(url.clone())
+        .send()
+        .await
+}
+
+/// A request holds its slot until the response body has been consumed.
+#[tokio::test]
+async fn limit_covers_response_body() -> Result<()> {
+    let url = start_server().await?;
+    let client = RegistryClientBuilder::new(Cache::temp()?.init()?)
+        .max_concurrent_requests_per_host(NonZeroUsize::MIN)
+        .build();
+
+    let first = send(&client, &url).await?;
+    assert_eq!(first.url(), &url);
+
+    // The first response occupies the only slot until its body is read.
+    let second = tokio::time::timeout(Duration::from_millis(200), send(&client, &url)).await;
+    assert!(second.is_err(), "the second request should wait for a slot");
+
+    assert_eq!(first.text().await?, "ok");
+
+    let second = tokio::time::timeout(Duration::from_secs(10), send(&client, &url)).await??;
+    assert_eq!(second.text().await?, "ok");
+
+    Ok(())
+}
+
+/// Dropping a response without reading its body releases its slot.
+#[tokio::test]
+async fn dropped_response_releases_slot() -> Result<()> {
+    let url = start_server().await?;
+    let client = RegistryClientBuilder::new(Cache::temp()?.init()?)
+        .max_concurrent_requests_per_host(NonZeroUsize::MIN)
+        .build();
+
+    drop(send(&client, &url).await?);
+
+    let response = tokio::time::timeout(Duration::from_secs(10), send(&client, &url)).await??;
+    assert_eq!(response.text().await?, "ok");
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+use uv_distribution_types::{IndexCapabilities, IndexUrl};
+use uv_normalize::PackageName;
+
+/// Start a simple index that responds to every request with the given status, returning the index
+/// URL and a counter of the requests it has served.
+async fn start_index(status: StatusCode) -> Result<(IndexUrl, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let counter = counter.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                future::ok::<_, hyper::Error>(
+                    Response::builder()
+                        .status(status)
+                        .header("content-type", "application/vnd.pypi.simple.v1+json")
+                        .body(Full::new(Bytes::from(r#"{"files": []}"#)))
+                        .unwrap(),
+                )
+            });
+            tokio::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await
+                    .unwrap();
+            });
+        }
+    });
+
+    Ok((
+        IndexUrl::from_str(&format!("http://{addr}/simple/"))?,
+        requests,
+    ))
+}
+
+/// An unavailable index fails over to its mirror, which is preferred from then on.
+#[tokio::test]
+async fn failover_to_mirror() -> Result<()> {
+    let (index, index_requests) = start_index(StatusCode::SERVICE_UNAVAILABLE).await?;
+    let (mirror, mirror_requests) = start_index(StatusCode::OK).await?;
+
+    let client = RegistryClientBuilder::new(Cache::temp()?.init()?)
+        .retries(0)
+        .index_mirrors(index.clone(), vec![mirror])
+        .build();
+    let package = PackageName::from_str("foo")?;
+    let capabilities = IndexCapabilities::default();
+
+    let results = client.simple(&package, Some(&index), &capabilities).await?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0, &index);
+    assert_eq!(index_requests.load(Ordering::SeqCst), 1);
+    assert_eq!(mirror_requests.load(Ordering::SeqCst), 1);
+
+    // The mirror responded, and the index didn't, so the mirror is tried first.
+    client.simple(&package, Some(&index), &capabilities).await?;
+    assert_eq!(index_requests.load(Ordering::SeqCst), 1);
+    assert_eq!(mirror_requests.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+/// The error from the last mirror is returned if every URL is unavailable.
+#[tokio::test]
+async fn all_mirrors_unavailable() -> Result<()> {
+    let (index, index_requests) = start_index(StatusCode::SERVICE_UNAVAILABLE).await?;
+    let (mirror, mirror_requests) = start_index(StatusCode::BAD_GATEWAY).await?;
+
+    let client = RegistryClientBuilder::new(Cache::temp()?.init()?)
+        .retries(0)
+        .index_mirrors(index.clone(), vec![mirror])
+        .build();
+    let package = PackageName::from_str("foo")?;
+
+    let err = client
+        .simple(&package, Some(&index), &IndexCapabilities::default())
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("Failed to fetch `foo` from index"),
+        "{err}"
+    );
+    assert!(err.to_string().contains("try again later"), "{err}");
+    assert_eq!(index_requests.load(Ordering::SeqCst), 1);
+    assert_eq!(mirror_requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -1,2 +1,6 @@
+mod cache_control;
+mod host_concurrency;
+mod index_mirrors;
 mod remote_metadata;
+mod resumable;
 mod user_agent_version;
@@ -0,0 +1,162 @@
+use std::convert::Infallible;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::{stream, StreamExt};
+use http_body_util::StreamBody;
+use hyper::body::{Bytes, Frame};
+use hyper::header::{
+    HeaderName, ACCEPT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE, ETAG, IF_RANGE, RANGE,
+};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{range_validator, resumable_stream, RegistryClientBuilder};
+
+const CONTENT: &[u8] = b"0123456789";
+
+/// The headers of a range request received by the server.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct RangeRequest {
+    range: String,
+    if_range: String,
+    accept_encoding: String,
+}
+
+/// Start a server that serves [`CONTENT`], but drops the connection halfway through the first
+/// response. If `ranges` is `true`, range requests are honored; otherwise, the full content is
+/// returned instead.
+async fn start_server(ranges: bool) -> Result<(Url, Arc<Mutex<Vec<RangeRequest>>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let received = received.clone();
+            let svc = service_fn(move |req: Request<hyper::body::Incoming>| {
+                let header = |name: HeaderName| {
+                    req.headers()
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                let range = header(RANGE);
+                let response = Response::builder().header(ETAG, "\"v1\"");
+                let response = if range.is_empty() {
+                    // Send the first half, then fail partway through.
+                    let head = Frame::data(Bytes::from_static(&CONTENT[..5]));
+                    let frames = stream::iter([Ok(head)]).chain(stream::once(async {
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                        Err(std::io::Error::other("connection reset"))
+                    }));
+                    response
+                        .header(CONTENT_LENGTH, CONTENT.len())
+                        .body(StreamBody::new(frames.boxed()))
+                } else {
+                    received.lock().unwrap().push(RangeRequest {
+                        range: range.clone(),
+                        if_range: header(IF_RANGE),
+                        accept_encoding: header(ACCEPT_ENCODING),
+                    });
+                    let offset = range
+                        .strip_prefix("bytes=")
+                        .and_then(|range| range.strip_suffix('-'))
+                        .and_then(|offset| offset.parse::<usize>().ok())
+                        .unwrap();
+                    let body = if ranges {
+                        Bytes::from_static(&CONTENT[offset..])
+                    } else {
+                        Bytes::from_static(CONTENT)
+                    };
+                    let response = if ranges {
+                        response.status(StatusCode::PARTIAL_CONTENT).header(
+                            CONTENT_RANGE,
+                            format!("bytes {offset}-{}/{}", CONTENT.len() - 1, CONTENT.len()),
+                        )
+                    } else {
+                        response
+                    };
+                    let frame: std::io::Result<_> = Ok(Frame::data(body.clone()));
+                    let frames = stream::iter([frame]);
+                    response
+                        .header(CONTENT_LENGTH, body.len())
+                        .body(StreamBody::new(frames.boxed()))
+                };
+                async move { Ok::<_, Infallible>(response.unwrap()) }
+            });
+            tokio::spawn(async move {
+                // The first response fails on purpose.
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(socket), svc)
+                    .await;
+            });
+        }
+    });
+
+    Ok((Url::parse(&format!("http://{addr}/foo.whl"))?, requests))
+}
+
+/// Download the content at `url`, resuming if the connection drops.
+async fn download(url: &Url) -> Result<Vec<u8>> {
+    let client = RegistryClientBuilder::new(Cache::temp()?.init()?).build();
+    let client = client.cached_client().uncached().for_host(url).clone();
+
+    let response = client.get(url.clone()).send().await?;
+    let validator = range_validator(&response);
+    assert!(validator.is_some());
+
+    let mut stream = pin!(resumable_stream(
+        client,
+        url.clone(),
+        response,
+        validator,
+        0,
+        3
+    ));
+    let mut content = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        content.extend_from_slice(chunk?.as_ref());
+    }
+    Ok(content)
+}
+
+/// An interrupted download is resumed with a range request.
+#[tokio::test]
+async fn resume_partial_content() -> Result<()> {
+    let (url, requests) = start_server(true).await?;
+
+    assert_eq!(download(&url).await?, CONTENT);
+    assert_eq!(
+        *requests.lock().unwrap(),
+        [RangeRequest {
+            range: "bytes=5-".to_string(),
+            if_range: "\"v1\"".to_string(),
+            accept_encoding: "identity".to_string(),
+        }]
+    );
+
+    Ok(())
+}
+
+/// If the server responds to the range request with the full content, the download fails rather
+/// than splicing together the two responses.
+#[tokio::test]
+async fn resume_full_content() -> Result<()> {
+    let (url, requests) = start_server(false).await?;
+
+    assert!(download(&url).await.is_err());
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    Ok(())
+}
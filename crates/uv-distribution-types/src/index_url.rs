@@ -11,7 +11,7 @@ use url::{ParseError, Url};
 
 use uv_pep508::{VerbatimUrl, VerbatimUrlError};
 
-use crate::{Index, Verbatim};
+use crate::{Index, IndexName, Verbatim};
 
 static PYPI_URL: LazyLock<Url> = LazyLock::new(|| Url::parse("https://pypi.org/simple").unwrap());
 
@@ -384,6 +384,14 @@ impl<'a> IndexUrls {
             .chain(self.default_index())
             .filter(|index| !index.explicit)
     }
+
+    /// Return the name of the index with the given URL, if it was defined with one.
+    pub fn name(&'a self, url: &IndexUrl) -> Option<&'a IndexName> {
+        self.indexes
+            .iter()
+            .find(|index| index.url == *url)
+            .and_then(|index| index.name.as_ref())
+    }
 }
 
 bitflags::bitflags! {
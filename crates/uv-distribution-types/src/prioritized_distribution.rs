@@ -308,6 +308,42 @@ impl PrioritizedDist {
         self.0.hashes.extend(hashes);
     }
 
+    /// Mark any distributions uploaded at or after the given timestamp (in milliseconds since the
+    /// Unix epoch), or without a known upload time, as incompatible.
+    pub fn exclude_newer(&mut self, timestamp_millis: i64) {
+        let excluded = |upload_time: Option<i64>| match upload_time {
+            Some(upload_time) => upload_time >= timestamp_millis,
+            None => true,
+        };
+
+        if let Some((sdist, compatibility)) = &mut self.0.source {
+            let upload_time = sdist.file.upload_time_utc_ms;
+            if excluded(upload_time) {
+                *compatibility = SourceDistCompatibility::Incompatible(
+                    IncompatibleSource::ExcludeNewer(upload_time),
+                );
+            }
+        }
+
+        for (wheel, compatibility) in &mut self.0.wheels {
+            let upload_time = wheel.file.upload_time_utc_ms;
+            if excluded(upload_time) {
+                *compatibility =
+                    WheelCompatibility::Incompatible(IncompatibleWheel::ExcludeNewer(upload_time));
+            }
+        }
+
+        // Re-select the highest-priority wheel.
+        let mut best_wheel_index = None;
+        for (index, (_, compatibility)) in self.0.wheels.iter().enumerate() {
+            match best_wheel_index {
+                Some(best) if !compatibility.is_more_compatible(&self.0.wheels[best].1) => {}
+                _ => best_wheel_index = Some(index),
+            }
+        }
+        self.0.best_wheel_index = best_wheel_index;
+    }
+
     /// Return the highest-priority distribution for the package version, if any.
     pub fn get(&self) -> Option<CompatibleDist> {
         let best_wheel = self.0.best_wheel_index.map(|i| &self.0.wheels[i]);
@@ -63,6 +63,31 @@ impl Resolution {
             })
     }
 
+    /// Return the [`ResolvedDist`] entities in this resolution in installation order, such that
+    /// every distribution appears after its dependencies.
+    ///
+    /// Distributions that form a dependency cycle can't be ordered relative to one another, so
+    /// they're returned adjacent to each other, sorted by name.
+    pub fn install_order(&self) -> Vec<&ResolvedDist> {
+        // Tarjan's algorithm returns the strongly connected components in reverse topological
+        // order, i.e., dependencies before dependents, with each cycle collapsed into a single
+        // component.
+        petgraph::algo::tarjan_scc(&self.graph)
+            .into_iter()
+            .flat_map(|component| {
+                let mut dists = component
+                    .into_iter()
+                    .filter_map(|index| match &self.graph[index] {
+                        Node::Dist { dist, install, .. } if *install => Some(dist),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                dists.sort_unstable_by(|a, b| a.name().cmp(b.name()));
+                dists
+            })
+            .collect()
+    }
+
     /// Return the number of distributions in this resolution.
     pub fn len(&self) -> usize {
         self.distributions().count()
@@ -279,3 +304,102 @@ impl From<&ResolvedDist> for RequirementSource {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use petgraph::graph::{DiGraph, NodeIndex};
+    use url::Url;
+
+    use uv_distribution_filename::DistExtension;
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+    use uv_pep508::{MarkerTree, VerbatimUrl};
+
+    use super::{Edge, Node, Resolution};
+    use crate::{Dist, Name, ResolvedDist};
+
+    /// Add a wheel for the given package to the graph.
+    fn add(graph: &mut DiGraph<Node, Edge>, name: &str) -> NodeIndex {
+        let url = Url::parse(&format!(
+            "https://example.com/{name}-1.0.0-py3-none-any.whl"
+        ))
+        .unwrap();
+        let dist = Dist::from_http_url(
+            PackageName::from_str(name).unwrap(),
+            VerbatimUrl::from_url(url.clone()),
+            url,
+            None,
+            DistExtension::Wheel,
+        )
+        .unwrap();
+        graph.add_node(Node::Dist {
+            dist: ResolvedDist::Installable {
+                dist,
+                version: Version::new([1, 0, 0]),
+            },
+            hashes: vec![],
+            install: true,
+        })
+    }
+
+    /// Build a resolution from the given dependency edges (`(dependent, dependency)`).
+    fn resolution(edges: &[(&str, &str)]) -> Resolution {
+        let mut graph = DiGraph::new();
+        let root = graph.add_node(Node::Root);
+        let mut nodes = std::collections::BTreeMap::new();
+        for (from, to) in edges {
+            for name in [*from, *to] {
+                if !nodes.contains_key(name) {
+                    let index = add(&mut graph, name);
+                    nodes.insert(name, index);
+                }
+            }
+            graph.add_edge(nodes[from], nodes[to], Edge::Prod(MarkerTree::TRUE));
+        }
+        // Every package without dependents is a direct dependency of the root.
+        for index in nodes.values() {
+            if graph
+                .neighbors_directed(*index, petgraph::Direction::Incoming)
+                .next()
+                .is_none()
+            {
+                graph.add_edge(root, *index, Edge::Prod(MarkerTree::TRUE));
+            }
+        }
+        Resolution::new(graph)
+    }
+
+    fn install_order(resolution: &Resolution) -> Vec<String> {
+        resolution
+            .install_order()
+            .into_iter()
+            .map(|dist| dist.name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn install_order_chain() {
+        let resolution = resolution(&[("a", "b"), ("b", "c")]);
+        assert_eq!(install_order(&resolution), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn install_order_cycle() {
+        // `b` and `c` depend on each other, so they're installed together (by name), after their
+        // dependency `d` and before their dependent `a`.
+        let resolution = resolution(&[("a", "c"), ("c", "b"), ("b", "c"), ("b", "d")]);
+        assert_eq!(install_order(&resolution), ["d", "b", "c", "a"]);
+    }
+
+    #[test]
+    fn install_order_disconnected() {
+        let resolution = resolution(&[("a", "b"), ("c", "d")]);
+        let order = install_order(&resolution);
+        assert_eq!(order.len(), 4);
+        let position = |name: &str| order.iter().position(|dist| dist == name).unwrap();
+        assert!(position("b") < position("a"));
+        assert!(position("d") < position("c"));
+    }
+}
@@ -6,9 +6,9 @@ use std::rc::Rc;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
-use futures::{FutureExt, TryStreamExt};
+use futures::{FutureExt, StreamExt, TryStreamExt};
 use tempfile::TempDir;
-use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
+use tokio::io::{AsyncRead, AsyncSeekExt, AsyncWriteExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{debug, info_span, instrument, warn, Instrument};
@@ -17,14 +17,15 @@ use url::Url;
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
-    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RangeResponse,
+    RegistryClient,
 };
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
     BuildableSource, BuiltDist, Dist, FileLocation, HashPolicy, Hashed, Name, SourceDist,
 };
 use uv_extract::hash::Hasher;
-use uv_fs::write_atomic;
+use uv_fs::{write_atomic, LockedFile};
 use uv_platform_tags::Tags;
 use uv_pypi_types::HashDigest;
 use uv_types::BuildContext;
@@ -185,7 +186,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 {
                     Ok(archive) => Ok(LocalWheel {
                         dist: Dist::Built(dist.clone()),
-                        archive: self.build_context.cache().archive(&archive.id),
+                        archive: self.build_context.cache().access_archive(&archive.id),
                         hashes: archive.hashes,
                         filename: wheel.filename.clone(),
                         cache: CacheInfo::default(),
@@ -216,7 +217,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
                         Ok(LocalWheel {
                             dist: Dist::Built(dist.clone()),
-                            archive: self.build_context.cache().archive(&archive.id),
+                            archive: self.build_context.cache().access_archive(&archive.id),
                             hashes: archive.hashes,
                             filename: wheel.filename.clone(),
                             cache: CacheInfo::default(),
@@ -248,7 +249,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 {
                     Ok(archive) => Ok(LocalWheel {
                         dist: Dist::Built(dist.clone()),
-                        archive: self.build_context.cache().archive(&archive.id),
+                        archive: self.build_context.cache().access_archive(&archive.id),
                         hashes: archive.hashes,
                         filename: wheel.filename.clone(),
                         cache: CacheInfo::default(),
@@ -272,7 +273,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                             .await?;
                         Ok(LocalWheel {
                             dist: Dist::Built(dist.clone()),
-                            archive: self.build_context.cache().archive(&archive.id),
+                            archive: self.build_context.cache().access_archive(&archive.id),
                             hashes: archive.hashes,
                             filename: wheel.filename.clone(),
                             cache: CacheInfo::default(),
@@ -344,7 +345,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         Ok(LocalWheel {
             dist: Dist::Source(dist.clone()),
-            archive: self.build_context.cache().archive(&id),
+            archive: self.build_context.cache().access_archive(&id),
             hashes: built_wheel.hashes,
             filename: built_wheel.filename,
             cache: built_wheel.cache_info,
@@ -491,10 +492,18 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
+                // If the connection drops partway through, resume the download where it left off.
+                let validator = uv_client::range_validator(&response);
+                let reader = Box::pin(uv_client::resumable_stream(
+                    self.client.unmanaged.uncached_client(&url).clone(),
+                    url.clone(),
+                    response,
+                    validator,
+                    0,
+                    self.client.unmanaged.cached_client().uncached().retries(),
+                ))
+                .map_err(|err| self.handle_response_errors(err))
+                .into_async_read();
 
                 // Create a hasher for each hash algorithm.
                 let algorithms = hashes.algorithms();
@@ -581,7 +590,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 .managed(|client| async {
                     client
                         .cached_client()
-                        .skip_cache_with_retry(self.request(url)?, &http_entry, download)
+                        .skip_cache_with_retry(self.request(url.clone())?, &http_entry, download)
                         .await
                         .map_err(|err| match err {
                             CachedClientError::Callback(err) => err,
@@ -616,39 +625,22 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
-
-                // Download the wheel to a temporary file.
-                let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
-                    .map_err(Error::CacheWrite)?;
-                let mut writer = tokio::io::BufWriter::new(tokio::fs::File::from_std(temp_file));
-
-                match progress {
-                    Some((reporter, progress)) => {
-                        // Wrap the reader in a progress reporter. This will report 100% progress
-                        // after the download is complete, even if we still have to unzip and hash
-                        // part of the file.
-                        let mut reader =
-                            ProgressReader::new(reader.compat(), progress, &**reporter);
-
-                        tokio::io::copy(&mut reader, &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
-                    }
-                    None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
-                    }
-                }
+                // Download the wheel to a partial file in the cache. This will report 100% progress
+                // after the download is complete, even if we still have to unzip and hash part of
+                // the file.
+                let partial_entry = wheel_entry.with_file(format!("{}.partial", filename.stem()));
+                let (mut file, _lock) = self
+                    .download_partial(
+                        &url,
+                        response,
+                        &partial_entry,
+                        progress.map(|(reporter, progress)| (&**reporter, progress)),
+                    )
+                    .await?;
 
                 // Unzip the wheel to a temporary directory.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
-                let mut file = writer.into_inner();
                 file.seek(io::SeekFrom::Start(0))
                     .await
                     .map_err(Error::CacheWrite)?;
@@ -680,6 +672,11 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     hashers.into_iter().map(HashDigest::from).collect()
                 };
 
+                // The download is complete, so the partial file is no longer needed.
+                fs_err::tokio::remove_file(partial_entry.path())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+
                 // Persist the temporary directory to the directory store.
                 let id = self
                     .build_context
@@ -738,7 +735,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 .managed(|client| async {
                     client
                         .cached_client()
-                        .skip_cache_with_retry(self.request(url)?, &http_entry, download)
+                        .skip_cache_with_retry(self.request(url.clone())?, &http_entry, download)
                         .await
                         .map_err(|err| match err {
                             CachedClientError::Callback(err) => err,
@@ -751,6 +748,129 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         Ok(archive)
     }
 
+    /// Download the body of `response` to the partial file at the given cache entry, returning
+    /// the complete file along with the lock that guards it.
+    ///
+    /// The partial file lives at a stable location in the cache, next to the validator (the `ETag`
+    /// or `Last-Modified` header) of the response it was written from. If the connection drops
+    /// partway through, the download is resumed via a range request, up to the client's configured
+    /// number of retries; if the download fails altogether, a later attempt picks up where this
+    /// one left off, provided the file hasn't changed on the server. Any hashes are verified
+    /// against the complete file by the caller.
+    async fn download_partial(
+        &self,
+        url: &Url,
+        response: reqwest::Response,
+        partial_entry: &CacheEntry,
+        progress: Option<(&dyn Reporter, usize)>,
+    ) -> Result<(tokio::fs::File, LockedFile), Error> {
+        let partial = partial_entry.path();
+        let validator_path = partial.with_extension("validator");
+
+        // Avoid concurrent writes to the same partial file.
+        fs_err::tokio::create_dir_all(partial_entry.dir())
+            .await
+            .map_err(Error::CacheWrite)?;
+        let lock = LockedFile::acquire(partial.with_extension("lock"), partial.display())
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        let client = self.client.unmanaged.uncached_client(url).clone();
+        let mut response = response;
+        let mut validator = uv_client::range_validator(&response);
+        let mut offset = 0;
+
+        // If a previous download of the same file was interrupted, request the remainder.
+        let previous = fs_err::tokio::read(&validator_path).await.ok();
+        let len = fs_err::tokio::metadata(partial)
+            .await
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        if let Some(current) = validator
+            .as_ref()
+            .filter(|current| len > 0 && previous.as_deref() == Some(current.as_bytes()))
+            .cloned()
+        {
+            // Drop the full response before issuing another request to the same host.
+            drop(response);
+            match uv_client::request_range(&client, url, &current, len).await? {
+                RangeResponse::Partial(resumed) => {
+                    debug!("Resuming download of {url} from {len} bytes");
+                    response = resumed;
+                    offset = len;
+                }
+                RangeResponse::Full(full) => {
+                    validator = uv_client::range_validator(&full);
+                    response = full;
+                }
+            }
+        }
+
+        // Record the validator, or remove a stale one, before writing any data.
+        if let Some(validator) = validator.as_ref() {
+            write_atomic(&validator_path, validator.as_bytes())
+                .await
+                .map_err(Error::CacheWrite)?;
+        } else {
+            match fs_err::tokio::remove_file(&validator_path).await {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(Error::CacheWrite(err)),
+            }
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(partial)
+            .await
+            .map_err(Error::CacheWrite)?;
+        file.set_len(offset).await.map_err(Error::CacheWrite)?;
+        file.seek(io::SeekFrom::Start(offset))
+            .await
+            .map_err(Error::CacheWrite)?;
+        if let Some((reporter, progress)) = progress.filter(|_| offset > 0) {
+            reporter.on_download_progress(progress, offset);
+        }
+
+        let mut stream = std::pin::pin!(uv_client::resumable_stream(
+            client,
+            url.clone(),
+            response,
+            validator,
+            offset,
+            self.client.unmanaged.cached_client().uncached().retries(),
+        ));
+        let mut writer = tokio::io::BufWriter::new(file);
+        while let Some(chunk) = stream.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    // Keep what we have, so that a later attempt can resume from it.
+                    writer.flush().await.map_err(Error::CacheWrite)?;
+                    return Err(Error::CacheWrite(self.handle_response_errors(err)));
+                }
+            };
+            writer
+                .write_all(chunk.as_ref())
+                .await
+                .map_err(Error::CacheWrite)?;
+            if let Some((reporter, progress)) = progress {
+                reporter.on_download_progress(progress, chunk.as_ref().len() as u64);
+            }
+        }
+        writer.flush().await.map_err(Error::CacheWrite)?;
+
+        // The file is complete, so there's nothing left to resume.
+        fs_err::tokio::remove_file(&validator_path)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok((writer.into_inner(), lock))
+    }
+
     /// Load a wheel from a local path.
     async fn load_wheel(
         &self,
@@ -777,7 +897,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         if let Some(archive) = archive {
             Ok(LocalWheel {
                 dist: Dist::Built(dist.clone()),
-                archive: self.build_context.cache().archive(&archive.id),
+                archive: self.build_context.cache().access_archive(&archive.id),
                 hashes: archive.hashes,
                 filename: filename.clone(),
                 cache: CacheInfo::from_timestamp(modified),
@@ -795,7 +915,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
             Ok(LocalWheel {
                 dist: Dist::Built(dist.clone()),
-                archive: self.build_context.cache().archive(&archive.id),
+                archive: self.build_context.cache().access_archive(&archive.id),
                 hashes: archive.hashes,
                 filename: filename.clone(),
                 cache: CacheInfo::from_timestamp(modified),
@@ -841,7 +961,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
             Ok(LocalWheel {
                 dist: Dist::Built(dist.clone()),
-                archive: self.build_context.cache().archive(&archive.id),
+                archive: self.build_context.cache().access_archive(&archive.id),
                 hashes: archive.hashes,
                 filename: filename.clone(),
                 cache: CacheInfo::from_timestamp(modified),
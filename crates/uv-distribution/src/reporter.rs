@@ -12,6 +12,10 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a source distribution build is complete.
     fn on_build_complete(&self, source: &BuildableSource, id: usize);
 
+    /// Callback to invoke when the metadata for a source distribution is read from the cache,
+    /// rather than built.
+    fn on_metadata_cached(&self, _source: &BuildableSource) {}
+
     /// Callback to invoke when a repository checkout begins.
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize;
 
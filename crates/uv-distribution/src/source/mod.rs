@@ -522,6 +522,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
         if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
             debug!("Using cached metadata for: {source}");
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_metadata_cached(source);
+            }
             return Ok(ArchiveMetadata {
                 metadata: Metadata::from_metadata23(metadata),
                 hashes: revision.into_hashes(),
@@ -826,6 +829,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
         if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
             debug!("Using cached metadata for: {source}");
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_metadata_cached(source);
+            }
             return Ok(ArchiveMetadata {
                 metadata: Metadata::from_metadata23(metadata),
                 hashes: revision.into_hashes(),
@@ -1097,6 +1103,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
         if let Some(metadata) = read_cached_metadata(&metadata_entry).await? {
             debug!("Using cached metadata for: {source}");
+            if let Some(reporter) = self.reporter.as_ref() {
+                reporter.on_metadata_cached(source);
+            }
             return Ok(ArchiveMetadata::from(
                 Metadata::from_workspace(
                     metadata,
@@ -1396,6 +1405,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 };
 
                 debug!("Using cached metadata for: {source}");
+                if let Some(reporter) = self.reporter.as_ref() {
+                    reporter.on_metadata_cached(source);
+                }
                 return Ok(ArchiveMetadata::from(
                     Metadata::from_workspace(
                         metadata,
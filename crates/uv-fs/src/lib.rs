@@ -257,6 +257,48 @@ pub async fn rename_with_retry(
     }
 }
 
+/// Rename a file, retrying (on Windows) if it fails due to transient operating system errors, in
+/// a synchronous context.
+pub fn rename_with_retry_sync(
+    from: impl AsRef<Path>,
+    to: impl AsRef<Path>,
+) -> Result<(), std::io::Error> {
+    if cfg!(windows) {
+        // On Windows, antivirus software can lock files temporarily, making them inaccessible.
+        // This is most common for DLLs, and the common suggestion is to retry the operation with
+        // some backoff.
+        //
+        // See: <https://github.com/astral-sh/uv/issues/1491>
+        let from = from.as_ref();
+        let to = to.as_ref();
+
+        let backoff = backoff::ExponentialBackoffBuilder::default()
+            .with_initial_interval(std::time::Duration::from_millis(10))
+            .with_max_elapsed_time(Some(std::time::Duration::from_secs(10)))
+            .build();
+
+        backoff::retry(backoff, || match fs_err::rename(from, to) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                warn!(
+                    "Retrying rename from {} to {} due to transient error: {}",
+                    from.display(),
+                    to.display(),
+                    err
+                );
+                Err(backoff::Error::transient(err))
+            }
+            Err(err) => Err(backoff::Error::permanent(err)),
+        })
+        .map_err(|err| match err {
+            backoff::Error::Permanent(err) => err,
+            backoff::Error::Transient { err, .. } => err,
+        })
+    } else {
+        fs_err::rename(from, to)
+    }
+}
+
 /// Iterate over the subdirectories of a directory.
 ///
 /// If the directory does not exist, returns an empty iterator.
@@ -384,6 +426,34 @@ impl LockedFile {
         Self::lock_file_blocking(file, &resource)
     }
 
+    /// Acquire a cross-process lock for a resource using an existing file at the provided path,
+    /// without waiting.
+    ///
+    /// Returns `None` if the lock is held by another process.
+    pub fn try_acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let file = fs_err::File::open(path.as_ref())?;
+        match file.file().try_lock_exclusive() {
+            Ok(()) => {
+                debug!("Acquired lock for `{resource}`");
+                Ok(Some(Self(file)))
+            }
+            Err(err) if err.raw_os_error() == fs2::lock_contended_error().raw_os_error() => {
+                trace!("Lock for `{resource}` is held by another process");
+                Ok(None)
+            }
+            Err(err) => Err(std::io::Error::new(
+                err.kind(),
+                format!(
+                    "Could not acquire lock for `{resource}` at `{}`: {err}",
+                    file.path().user_display()
+                ),
+            )),
+        }
+    }
+
     /// Acquire a cross-process lock for a resource using a file at the provided path.
     #[cfg(feature = "tokio")]
     pub async fn acquire(
@@ -333,6 +333,11 @@ impl<'a> Planner<'a> {
             }
         }
 
+        // Record that the cached wheels are in use, to inform cache eviction.
+        for dist in &cached {
+            cache.touch_archive(dist.path());
+        }
+
         Ok(Plan {
             cached,
             remote,
@@ -1,7 +1,11 @@
 use std::borrow::Borrow;
+use std::future::Future;
 use std::hash::{BuildHasher, Hash, RandomState};
 use std::pin::pin;
 use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::Thread;
+use std::time::Instant;
 
 use dashmap::DashMap;
 use tokio::sync::Notify;
@@ -94,6 +98,40 @@ impl<K: Eq + Hash, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
         futures::executor::block_on(self.wait(key))
     }
 
+    /// Wait for the result of a job that is running, in a blocking context, giving up once the
+    /// deadline (if any) has passed or the `interrupt` future completes, whichever comes first.
+    ///
+    /// Returns [`Interrupted`] if [`OnceMap::done`] isn't called for this key before then.
+    pub fn wait_blocking_until(
+        &self,
+        key: &K,
+        deadline: Option<Instant>,
+        interrupt: impl Future<Output = ()>,
+    ) -> Result<Option<V>, Interrupted> {
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut context = Context::from_waker(&waker);
+        let mut future = pin!(self.wait(key));
+        let mut interrupt = pin!(interrupt);
+        loop {
+            if let Poll::Ready(value) = future.as_mut().poll(&mut context) {
+                return Ok(value);
+            }
+            if interrupt.as_mut().poll(&mut context).is_ready() {
+                return Err(Interrupted);
+            }
+            // Spurious wakeups are fine, since we poll again either way.
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(Interrupted);
+                }
+                std::thread::park_timeout(deadline - now);
+            } else {
+                std::thread::park();
+            }
+        }
+    }
+
     /// Return the result of a previous job, if any.
     pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<V>
     where
@@ -142,7 +180,83 @@ where
     }
 }
 
+/// The deadline passed, or the wait was interrupted, before the result of a job was available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interrupted;
+
+/// Wakes a thread that's blocked in [`OnceMap::wait_blocking_until`].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
 enum Value<V> {
     Waiting(Arc<Notify>),
     Filled(V),
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use super::{Interrupted, OnceMap};
+
+    #[test]
+    fn wait_blocking_until() {
+        let map = Arc::new(OnceMap::<&str, u32>::default());
+
+        // Unregistered keys return immediately.
+        let deadline = Instant::now() + Duration::from_secs(60);
+        assert_eq!(
+            map.wait_blocking_until(&"a", Some(deadline), std::future::pending()),
+            Ok(None)
+        );
+
+        // A job that never finishes times out.
+        assert!(map.register("a"));
+        let deadline = Instant::now() + Duration::from_millis(50);
+        assert_eq!(
+            map.wait_blocking_until(&"a", Some(deadline), std::future::pending()),
+            Err(Interrupted)
+        );
+        assert!(Instant::now() >= deadline);
+
+        // An interrupt on another thread wakes the waiter.
+        let (sender, receiver) = tokio::sync::oneshot::channel::<()>();
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            sender.send(()).unwrap();
+        });
+        let interrupt = async {
+            let _ = receiver.await;
+        };
+        assert_eq!(
+            map.wait_blocking_until(&"a", None, interrupt),
+            Err(Interrupted)
+        );
+        handle.join().unwrap();
+
+        // A job that finishes on another thread wakes the waiter.
+        let handle = std::thread::spawn({
+            let map = map.clone();
+            move || {
+                std::thread::sleep(Duration::from_millis(50));
+                map.done("a", 1);
+            }
+        });
+        let deadline = Instant::now() + Duration::from_secs(60);
+        assert_eq!(
+            map.wait_blocking_until(&"a", Some(deadline), std::future::pending()),
+            Ok(Some(1))
+        );
+        handle.join().unwrap();
+    }
+}
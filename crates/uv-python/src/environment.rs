@@ -44,6 +44,7 @@ pub struct InvalidEnvironment {
 pub enum InvalidEnvironmentKind {
     NotDirectory,
     MissingExecutable(PathBuf),
+    MissingBaseInterpreter(PathBuf),
 }
 
 impl From<PythonNotFound> for EnvironmentNotFound {
@@ -128,6 +129,13 @@ impl std::fmt::Display for InvalidEnvironmentKind {
             Self::MissingExecutable(path) => {
                 write!(f, "missing Python executable at `{}`", path.user_display())
             }
+            Self::MissingBaseInterpreter(home) => {
+                write!(
+                    f,
+                    "the base Python installation at `{}` no longer exists",
+                    home.user_display()
+                )
+            }
         }
     }
 }
@@ -191,6 +199,21 @@ impl PythonEnvironment {
             .into());
         };
 
+        // If the base interpreter was upgraded or removed, the `home` recorded in `pyvenv.cfg` will
+        // be stale, and querying the environment's executable will fail with an opaque error.
+        if let Ok(cfg) = PyVenvConfiguration::parse(venv.join("pyvenv.cfg")) {
+            if let Some(home) = cfg
+                .home()
+                .filter(|home| home.is_absolute() && !home.is_dir())
+            {
+                return Err(InvalidEnvironment {
+                    kind: InvalidEnvironmentKind::MissingBaseInterpreter(home.to_path_buf()),
+                    path: venv,
+                }
+                .into());
+            }
+        }
+
         let interpreter = Interpreter::query(executable, cache)?;
 
         Ok(Self(Arc::new(PythonEnvironmentShared {
@@ -14,15 +14,16 @@ use tracing::{trace, warn};
 use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
 use uv_cache_info::Timestamp;
 use uv_cache_key::cache_digest;
+use uv_configuration::TargetTriple;
 use uv_fs::{write_atomic_sync, PythonExt, Simplified};
 use uv_install_wheel::Layout;
 use uv_pep440::Version;
-use uv_pep508::{MarkerEnvironment, StringVersion};
+use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder, StringVersion};
 use uv_platform_tags::Platform;
 use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::{ResolverMarkerEnvironment, Scheme};
 
-use crate::implementation::LenientImplementationName;
+use crate::implementation::{ImplementationName, LenientImplementationName};
 use crate::platform::{Arch, Libc, Os};
 use crate::pointer_size::PointerSize;
 use crate::{
@@ -51,6 +52,8 @@ pub struct Interpreter {
     prefix: Option<Prefix>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    enable_shared: bool,
+    conda: bool,
 }
 
 impl Interpreter {
@@ -65,6 +68,7 @@ impl Interpreter {
         );
 
         Ok(Self {
+            conda: is_conda_prefix(&info.sys_prefix),
             platform: info.platform,
             markers: Box::new(info.markers),
             scheme: info.scheme,
@@ -74,6 +78,7 @@ impl Interpreter {
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
+            enable_shared: info.enable_shared,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -86,12 +91,128 @@ impl Interpreter {
         })
     }
 
+    /// Construct an [`Interpreter`] for a Python installation that isn't available locally, e.g.,
+    /// to resolve for a different target or in tests.
+    ///
+    /// The platform and its markers are derived from the [`TargetTriple`], as for
+    /// `--python-platform`. The implementation version (e.g., `7.3.17` for PyPy) defaults to the
+    /// Python version, which is only correct for CPython. The install scheme is rooted at
+    /// `sys_prefix`, which need not exist: the resulting interpreter can be used for resolution,
+    /// but not to install packages.
+    pub fn artificial(
+        target: TargetTriple,
+        implementation: ImplementationName,
+        implementation_version: Option<&Version>,
+        python_version: &PythonVersion,
+        gil_disabled: bool,
+        sys_prefix: PathBuf,
+    ) -> Self {
+        let platform = target.platform();
+        let python_full_version = python_version.python_full_version().to_string();
+        let python_minor_version = python_version.python_version().to_string();
+        let implementation_version =
+            implementation_version.map_or_else(|| python_full_version.clone(), ToString::to_string);
+        let platform_python_implementation = match implementation {
+            ImplementationName::CPython => "CPython",
+            ImplementationName::PyPy => "PyPy",
+            ImplementationName::GraalPy => "GraalVM",
+        };
+
+        let is_windows = matches!(platform.os(), uv_platform_tags::Os::Windows);
+        let markers = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name: implementation.into(),
+            implementation_version: &implementation_version,
+            os_name: target.os_name(),
+            platform_machine: target.platform_machine(),
+            platform_python_implementation,
+            platform_release: target.platform_release(),
+            platform_system: target.platform_system(),
+            platform_version: target.platform_version(),
+            python_full_version: &python_full_version,
+            python_version: &python_minor_version,
+            sys_platform: target.sys_platform(),
+        })
+        .expect("artificial markers are always valid");
+
+        // Mirror the default `sysconfig` scheme for the platform, relative to the prefix.
+        let abiflags = if gil_disabled { "t" } else { "" };
+        let lib = match implementation {
+            ImplementationName::PyPy => format!("pypy{python_minor_version}"),
+            _ => format!("python{python_minor_version}{abiflags}"),
+        };
+        let (stdlib, virtualenv) = if is_windows {
+            (
+                PathBuf::from("Lib"),
+                Scheme {
+                    purelib: PathBuf::from("Lib").join("site-packages"),
+                    platlib: PathBuf::from("Lib").join("site-packages"),
+                    scripts: PathBuf::from("Scripts"),
+                    data: PathBuf::new(),
+                    include: PathBuf::from("Include"),
+                },
+            )
+        } else {
+            (
+                PathBuf::from("lib").join(&lib),
+                Scheme {
+                    purelib: PathBuf::from("lib").join(&lib).join("site-packages"),
+                    platlib: PathBuf::from("lib").join(&lib).join("site-packages"),
+                    scripts: PathBuf::from("bin"),
+                    data: PathBuf::new(),
+                    include: PathBuf::from("include").join(&lib),
+                },
+            )
+        };
+        let scheme = Scheme {
+            purelib: sys_prefix.join(&virtualenv.purelib),
+            platlib: sys_prefix.join(&virtualenv.platlib),
+            scripts: sys_prefix.join(&virtualenv.scripts),
+            data: sys_prefix.join(&virtualenv.data),
+            include: sys_prefix.join(&virtualenv.include),
+        };
+        let sys_executable = if is_windows {
+            sys_prefix.join("python.exe")
+        } else {
+            sys_prefix.join("bin").join("python3")
+        };
+        let pointer_size = match platform.arch() {
+            uv_platform_tags::Arch::X86
+            | uv_platform_tags::Arch::Armv6L
+            | uv_platform_tags::Arch::Armv7L => PointerSize::_32,
+            _ => PointerSize::_64,
+        };
+
+        Self {
+            manylinux_compatible: matches!(platform.os(), uv_platform_tags::Os::Manylinux { .. }),
+            platform,
+            markers: Box::new(markers),
+            scheme,
+            virtualenv,
+            sys_base_exec_prefix: sys_prefix.clone(),
+            sys_base_prefix: sys_prefix.clone(),
+            sys_base_executable: Some(sys_executable.clone()),
+            sys_path: vec![sys_prefix.join(&stdlib)],
+            stdlib: sys_prefix.join(stdlib),
+            sys_executable,
+            sys_prefix,
+            sysconfig_prefix: None,
+            tags: OnceLock::new(),
+            target: None,
+            prefix: None,
+            pointer_size,
+            gil_disabled,
+            enable_shared: false,
+            conda: false,
+        }
+    }
+
     /// Return a new [`Interpreter`] with the given virtual environment root.
     #[must_use]
     pub fn with_virtualenv(self, virtualenv: VirtualEnvironment) -> Self {
         Self {
             scheme: virtualenv.scheme,
             sys_executable: virtualenv.executable,
+            conda: is_conda_prefix(&virtualenv.root),
             sys_prefix: virtualenv.root,
             target: None,
             prefix: None,
@@ -215,6 +336,17 @@ impl Interpreter {
         self.sys_prefix != self.sys_base_prefix
     }
 
+    /// Returns `true` if the environment is a Conda environment, i.e., if `sys.prefix` contains a
+    /// `conda-meta` directory.
+    ///
+    /// Conda environments are not PEP 405-compliant virtual environments, as `sys.prefix` matches
+    /// `sys.base_prefix`, but they're isolated from the base installation in the same way.
+    /// Installing into one needs no special handling, since the [`Scheme`] is queried from the
+    /// environment's own interpreter and so already points into the environment.
+    pub fn is_conda(&self) -> bool {
+        self.conda
+    }
+
     /// Returns `true` if the environment is a `--target` environment.
     pub fn is_target(&self) -> bool {
         self.target.is_some()
@@ -421,6 +553,15 @@ impl Interpreter {
         self.gil_disabled
     }
 
+    /// Returns `true` if the interpreter links against a shared `libpython`, i.e., if it was built
+    /// with `--enable-shared`.
+    ///
+    /// The executables of such builds typically locate `libpython` relative to themselves (e.g.,
+    /// via an `$ORIGIN/../lib` rpath), so they can't be copied elsewhere.
+    pub fn enable_shared(&self) -> bool {
+        self.enable_shared
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -540,6 +681,11 @@ impl Interpreter {
     }
 }
 
+/// Returns `true` if the given `sys.prefix` is the root of a Conda environment.
+fn is_conda_prefix(sys_prefix: &Path) -> bool {
+    sys_prefix.join("conda-meta").is_dir()
+}
+
 /// The `EXTERNALLY-MANAGED` file in a Python installation.
 ///
 /// See: <https://packaging.python.org/en/latest/specifications/externally-managed-environments/>
@@ -628,6 +774,7 @@ struct InterpreterInfo {
     sysconfig_prefix: Option<PathBuf>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    enable_shared: bool,
 }
 
 impl InterpreterInfo {
@@ -831,9 +978,10 @@ mod tests {
     use tempfile::tempdir;
 
     use uv_cache::Cache;
+    use uv_configuration::TargetTriple;
     use uv_pep440::Version;
 
-    use crate::Interpreter;
+    use crate::{ImplementationName, Interpreter, PythonVersion};
 
     #[test]
     fn test_cache_invalidation() {
@@ -888,7 +1036,8 @@ mod tests {
                 "scripts": "bin"
             },
             "pointer_size": "64",
-            "gil_disabled": true
+            "gil_disabled": true,
+            "enable_shared": false
         }
     "##};
 
@@ -927,4 +1076,71 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[test]
+    fn artificial() {
+        let interpreter = Interpreter::artificial(
+            TargetTriple::X8664PcWindowsMsvc,
+            ImplementationName::CPython,
+            None,
+            &PythonVersion::from_str("3.13").unwrap(),
+            true,
+            std::path::PathBuf::from("python"),
+        );
+
+        let markers = interpreter.markers();
+        assert_eq!(markers.implementation_name(), "cpython");
+        assert_eq!(markers.implementation_version().to_string(), "3.13.0");
+        assert_eq!(markers.python_full_version().to_string(), "3.13.0");
+        assert_eq!(markers.sys_platform(), "win32");
+        assert_eq!(markers.os_name(), "nt");
+        assert_eq!(markers.platform_machine(), "x86_64");
+        assert_eq!(
+            interpreter.tags().unwrap().to_string().lines().next(),
+            Some("cp313-cp313t-win_amd64")
+        );
+
+        let interpreter = Interpreter::artificial(
+            TargetTriple::Aarch64UnknownLinuxGnu,
+            ImplementationName::PyPy,
+            Some(&Version::from_str("7.3.19").unwrap()),
+            &PythonVersion::from_str("3.10").unwrap(),
+            false,
+            std::path::PathBuf::from("python"),
+        );
+
+        let markers = interpreter.markers();
+        assert_eq!(markers.implementation_name(), "pypy");
+        assert_eq!(markers.implementation_version().to_string(), "7.3.19");
+        assert_eq!(markers.platform_python_implementation(), "PyPy");
+        assert_eq!(markers.python_full_version().to_string(), "3.10.0");
+        assert_eq!(markers.sys_platform(), "linux");
+        assert_eq!(markers.os_name(), "posix");
+        assert_eq!(markers.platform_system(), "Linux");
+        assert_eq!(markers.platform_machine(), "aarch64");
+        assert_eq!(markers.platform_release(), "");
+        assert_eq!(markers.platform_version(), "");
+        assert!(interpreter
+            .tags()
+            .unwrap()
+            .to_string()
+            .lines()
+            .any(|tag| tag == "pp310-pypy310_pp73-manylinux_2_17_aarch64"));
+
+        let interpreter = Interpreter::artificial(
+            TargetTriple::Aarch64AppleDarwin,
+            ImplementationName::GraalPy,
+            Some(&Version::from_str("24.2.0").unwrap()),
+            &PythonVersion::from_str("3.11").unwrap(),
+            false,
+            std::path::PathBuf::from("python"),
+        );
+
+        let markers = interpreter.markers();
+        assert_eq!(markers.implementation_name(), "graalpy");
+        assert_eq!(markers.implementation_version().to_string(), "24.2.0");
+        assert_eq!(markers.platform_python_implementation(), "GraalVM");
+        assert_eq!(markers.sys_platform(), "darwin");
+        assert_eq!(markers.platform_machine(), "arm64");
+    }
 }
@@ -26,6 +26,8 @@ pub struct VirtualEnvironment {
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PyVenvConfiguration {
+    /// The directory containing the base Python interpreter, per the `home` key.
+    pub(crate) home: Option<PathBuf>,
     /// Was the virtual environment created with the `virtualenv` package?
     pub(crate) virtualenv: bool,
     /// Was the virtual environment created with the `uv` package?
@@ -34,6 +36,8 @@ pub struct PyVenvConfiguration {
     pub(crate) relocatable: bool,
     /// Was the virtual environment populated with seed packages?
     pub(crate) seed: bool,
+    /// All entries in the file, in order, including those not recognized by uv.
+    pub(crate) entries: Vec<(String, String)>,
 }
 
 #[derive(Debug, Error)]
@@ -181,10 +185,12 @@ pub(crate) fn virtualenv_python_executable(venv: impl AsRef<Path>) -> PathBuf {
 impl PyVenvConfiguration {
     /// Parse a `pyvenv.cfg` file into a [`PyVenvConfiguration`].
     pub fn parse(cfg: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut home = None;
         let mut virtualenv = false;
         let mut uv = false;
         let mut relocatable = false;
         let mut seed = false;
+        let mut entries = Vec::new();
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -195,7 +201,11 @@ impl PyVenvConfiguration {
             let Some((key, value)) = line.split_once('=') else {
                 continue;
             };
+            entries.push((key.trim().to_string(), value.trim().to_string()));
             match key.trim() {
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
                 "virtualenv" => {
                     virtualenv = true;
                 }
@@ -213,13 +223,20 @@ impl PyVenvConfiguration {
         }
 
         Ok(Self {
+            home,
             virtualenv,
             uv,
             relocatable,
             seed,
+            entries,
         })
     }
 
+    /// Returns the directory containing the base Python interpreter, if set.
+    pub fn home(&self) -> Option<&Path> {
+        self.home.as_deref()
+    }
+
     /// Returns true if the virtual environment was created with the `virtualenv` package.
     pub fn is_virtualenv(&self) -> bool {
         self.virtualenv
@@ -239,4 +256,23 @@ impl PyVenvConfiguration {
     pub fn is_seed(&self) -> bool {
         self.seed
     }
+
+    /// Returns the value for the given key, if present.
+    ///
+    /// If the key appears multiple times, the last value wins, matching CPython's `site` module.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns an iterator over all entries in the file, in order, including those that uv
+    /// doesn't recognize.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
 }
@@ -28,7 +28,7 @@ pub(crate) struct CandidateSelector {
 impl CandidateSelector {
     /// Return a [`CandidateSelector`] for the given [`Manifest`].
     pub(crate) fn for_resolution(
-        options: Options,
+        options: &Options,
         manifest: &Manifest,
         env: &ResolverEnvironment,
     ) -> Self {
@@ -41,6 +41,7 @@ impl CandidateSelector {
             ),
             prerelease_strategy: PrereleaseStrategy::from_mode(
                 options.prerelease_mode,
+                &options.prerelease_packages,
                 manifest,
                 env,
                 options.dependency_mode,
@@ -1,8 +1,10 @@
 use std::collections::{BTreeMap, BTreeSet, Bound};
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Duration;
 
 use indexmap::IndexSet;
+use owo_colors::OwoColorize;
 use pubgrub::{
     DefaultStringReporter, DerivationTree, Derived, External, Range, Ranges, Reporter, Term,
 };
@@ -13,8 +15,10 @@ use uv_distribution_types::{
     BuiltDist, DerivationChain, IndexCapabilities, IndexLocations, IndexUrl, InstalledDist,
     SourceDist,
 };
-use uv_normalize::{ExtraName, PackageName};
+use uv_fs::Simplified;
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{LocalVersionSlice, Version};
+use uv_pep508::RequirementOrigin;
 use uv_static::EnvVars;
 
 use crate::candidate_selector::CandidateSelector;
@@ -24,7 +28,7 @@ use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
-    IncompletePackage, ResolverEnvironment, UnavailablePackage, UnavailableReason,
+    ExploredPackage, IncompletePackage, ResolverEnvironment, UnavailablePackage, UnavailableReason,
 };
 use crate::Options;
 
@@ -143,6 +147,27 @@ pub enum ResolveError {
 
     #[error("Package `{0}` is unavailable")]
     PackageUnavailable(PackageName),
+
+    #[error(
+        "Resolution did not complete within {timeout:?}{}",
+        format_most_explored(packages)
+    )]
+    Timeout {
+        timeout: Duration,
+        packages: Vec<ExploredPackage>,
+    },
+
+    #[error(
+        "Resolution did not complete within {limit} decisions{}",
+        format_most_explored(packages)
+    )]
+    BacktrackingLimit {
+        limit: usize,
+        packages: Vec<ExploredPackage>,
+    },
+
+    #[error("Resolution was cancelled{}", format_most_explored(packages))]
+    Cancelled { packages: Vec<ExploredPackage> },
 }
 
 impl<T> From<tokio::sync::mpsc::error::SendError<T>> for ResolveError {
@@ -171,6 +196,7 @@ pub struct NoSolutionError {
     env: ResolverEnvironment,
     workspace_members: BTreeSet<PackageName>,
     options: Options,
+    inputs: FxHashMap<PackageName, Vec<InputRequirement>>,
 }
 
 impl NoSolutionError {
@@ -189,6 +215,7 @@ impl NoSolutionError {
         env: ResolverEnvironment,
         workspace_members: BTreeSet<PackageName>,
         options: Options,
+        inputs: FxHashMap<PackageName, Vec<InputRequirement>>,
     ) -> Self {
         Self {
             error,
@@ -204,6 +231,7 @@ impl NoSolutionError {
             env,
             workspace_members,
             options,
+            inputs,
         }
     }
 
@@ -321,31 +349,52 @@ impl NoSolutionError {
         strip(derivation_tree).expect("derivation tree should contain at least one term")
     }
 
+    /// Return the input requirements, constraints, and overrides for the given package that are
+    /// involved in the resolution failure.
+    ///
+    /// Each input carries its [`RequirementOrigin`](uv_pep508::RequirementOrigin), if known (e.g.,
+    /// the `requirements.txt` or `pyproject.toml` file that declared it), such that tooling can
+    /// map a conflict back to the offending line.
+    pub fn inputs(&self, name: &PackageName) -> &[InputRequirement] {
+        self.inputs.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Return the input requirements that are involved in the failure, i.e., the requirements,
+    /// constraints, and overrides for the dependencies of the root package (and of any workspace
+    /// members) that appear in the derivation tree.
+    ///
+    /// Since the tree is a proof that the requirements can't be satisfied together, these form a
+    /// small subset of the inputs that is, on its own, unsatisfiable. Inputs that don't contribute
+    /// to the conflict are omitted.
+    pub fn root_requirements(&self) -> Vec<&InputRequirement> {
+        root_dependencies(&self.error, &self.workspace_members)
+            .iter()
+            .flat_map(|name| self.inputs(name))
+            .collect()
+    }
+
     /// Initialize a [`NoSolutionHeader`] for this error.
     pub fn header(&self) -> NoSolutionHeader {
         NoSolutionHeader::new(self.env.clone())
     }
-}
-
-impl std::error::Error for NoSolutionError {}
 
-impl std::fmt::Display for NoSolutionError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        // Write the derivation report.
-        let formatter = PubGrubReportFormatter {
-            available_versions: &self.available_versions,
-            python_requirement: &self.python_requirement,
-            workspace_members: &self.workspace_members,
-        };
+    /// Return a structured view of the derivation tree that explains the resolution failure.
+    ///
+    /// The tree is reduced in the same way as the rendered report (e.g., proxy packages and
+    /// redundant incompatibilities are collapsed), such that callers can render their own
+    /// explanation of the failure.
+    pub fn derivation_tree(&self) -> NoSolutionTree {
+        NoSolutionTree::from(&self.reduced_tree(false))
+    }
 
-        // Transform the error tree for reporting
+    /// Simplify and reduce the derivation tree for reporting.
+    ///
+    /// If `show` is `true`, the tree is logged before and after the reduction.
+    fn reduced_tree(&self, show: bool) -> ErrorTree {
         let mut tree = self.error.clone();
         simplify_derivation_tree_markers(&self.python_requirement, &mut tree);
-        let should_display_tree = std::env::var_os(EnvVars::UV_INTERNAL__SHOW_DERIVATION_TREE)
-            .is_some()
-            || tracing::enabled!(tracing::Level::TRACE);
 
-        if should_display_tree {
+        if show {
             display_tree(&tree, "Resolver derivation tree before reduction");
         }
 
@@ -359,10 +408,31 @@ impl std::fmt::Display for NoSolutionError {
         collapse_unavailable_versions(&mut tree);
         collapse_redundant_depends_on_no_versions(&mut tree);
 
-        if should_display_tree {
+        if show {
             display_tree(&tree, "Resolver derivation tree after reduction");
         }
 
+        tree
+    }
+}
+
+impl std::error::Error for NoSolutionError {}
+
+impl std::fmt::Display for NoSolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Write the derivation report.
+        let formatter = PubGrubReportFormatter {
+            available_versions: &self.available_versions,
+            python_requirement: &self.python_requirement,
+            workspace_members: &self.workspace_members,
+        };
+
+        // Transform the error tree for reporting
+        let should_display_tree = std::env::var_os(EnvVars::UV_INTERNAL__SHOW_DERIVATION_TREE)
+            .is_some()
+            || tracing::enabled!(tracing::Level::TRACE);
+        let tree = self.reduced_tree(should_display_tree);
+
         let report = DefaultStringReporter::report_with_formatter(&tree, &formatter);
         write!(f, "{report}")?;
 
@@ -379,17 +449,42 @@ impl std::fmt::Display for NoSolutionError {
             &self.fork_urls,
             &self.env,
             &self.workspace_members,
-            self.options,
+            &self.options,
             &mut additional_hints,
         );
         for hint in additional_hints {
             write!(f, "\n\n{hint}")?;
         }
 
+        // Point at the origin of any constraints or overrides involved in the failure.
+        for hint in self
+            .root_requirements()
+            .into_iter()
+            .filter_map(input_origin_hint)
+            .collect::<IndexSet<_>>()
+        {
+            write!(f, "\n\n{hint}")?;
+        }
+
         Ok(())
     }
 }
 
+/// Format the most explored packages as a suffix for a resolver budget error.
+fn format_most_explored(packages: &[ExploredPackage]) -> String {
+    if packages.is_empty() {
+        return String::new();
+    }
+    format!(
+        "; the most explored packages were: {}",
+        packages
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
 #[allow(clippy::print_stderr)]
 fn display_tree(
     error: &DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>,
@@ -742,6 +837,50 @@ fn collapse_unavailable_versions(
     }
 }
 
+/// Return the names of the packages that the root package, or a workspace member, depends on
+/// anywhere in the derivation tree, in order of first appearance.
+///
+/// Dependencies on the workspace members themselves are omitted, since the user-facing inputs are
+/// the members' own requirements.
+fn root_dependencies(
+    tree: &ErrorTree,
+    workspace_members: &BTreeSet<PackageName>,
+) -> Vec<PackageName> {
+    fn collect(
+        tree: &ErrorTree,
+        workspace_members: &BTreeSet<PackageName>,
+        dependencies: &mut Vec<PackageName>,
+    ) {
+        match tree {
+            DerivationTree::External(External::FromDependencyOf(package, _, dependency, _)) => {
+                let is_root = matches!(&**package, PubGrubPackageInner::Root(_))
+                    || package
+                        .name()
+                        .is_some_and(|name| workspace_members.contains(name));
+                if !is_root {
+                    return;
+                }
+                let Some(name) = dependency.name() else {
+                    return;
+                };
+                if workspace_members.contains(name) || dependencies.contains(name) {
+                    return;
+                }
+                dependencies.push(name.clone());
+            }
+            DerivationTree::External(_) => {}
+            DerivationTree::Derived(derived) => {
+                collect(&derived.cause1, workspace_members, dependencies);
+                collect(&derived.cause2, workspace_members, dependencies);
+            }
+        }
+    }
+
+    let mut dependencies = Vec::new();
+    collect(tree, workspace_members, &mut dependencies);
+    dependencies
+}
+
 /// Given a [`DerivationTree`], drop dependency incompatibilities from the root
 /// to the project.
 ///
@@ -950,3 +1089,426 @@ impl std::fmt::Display for NoSolutionHeader {
         }
     }
 }
+
+/// An input to the resolver, along with its role.
+#[derive(Debug, Clone)]
+pub enum InputRequirement {
+    /// A requirement.
+    Requirement(uv_pypi_types::Requirement),
+    /// A constraint.
+    Constraint(uv_pypi_types::Requirement),
+    /// An override.
+    Override(uv_pypi_types::Requirement),
+}
+
+impl InputRequirement {
+    /// Return the underlying [`uv_pypi_types::Requirement`].
+    pub fn requirement(&self) -> &uv_pypi_types::Requirement {
+        match self {
+            Self::Requirement(requirement)
+            | Self::Constraint(requirement)
+            | Self::Override(requirement) => requirement,
+        }
+    }
+}
+
+impl std::fmt::Display for InputRequirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (kind, requirement) = match self {
+            Self::Requirement(requirement) => ("requirement", requirement),
+            Self::Constraint(requirement) => ("constraint", requirement),
+            Self::Override(requirement) => ("override", requirement),
+        };
+        write!(f, "{kind} `{requirement}`")?;
+        if let Some(origin) = &requirement.origin {
+            write!(f, " from `{}`", origin.path().user_display())?;
+        }
+        Ok(())
+    }
+}
+
+/// Format a hint that points at the file that declared a constraint or override involved in a
+/// resolution failure.
+///
+/// Requirements are omitted, since they're typically declared in the files the user is resolving
+/// and are already named in the report, while constraints and overrides are easy to lose track of.
+fn input_origin_hint(input: &InputRequirement) -> Option<String> {
+    let (kind, requirement) = match input {
+        InputRequirement::Requirement(_) => return None,
+        InputRequirement::Constraint(requirement) => ("constraint", requirement),
+        InputRequirement::Override(requirement) => ("override", requirement),
+    };
+    let origin = match requirement.origin.as_ref()? {
+        RequirementOrigin::File(path) | RequirementOrigin::Project(path, _) => path,
+        RequirementOrigin::Workspace => return None,
+    };
+    Some(format!(
+        "{}{} The {kind} `{}` was declared in `{}`",
+        "hint".bold().cyan(),
+        ":".bold(),
+        requirement.to_string().bold(),
+        origin.user_display().cyan(),
+    ))
+}
+
+/// A structured view of the derivation tree behind a [`NoSolutionError`].
+///
+/// Each leaf is an external fact about the packages involved (e.g., "no versions of `foo`
+/// match `>=2`"), and each inner node is an incompatibility derived from its two causes.
+#[derive(Debug, Clone)]
+pub enum NoSolutionTree {
+    /// The root package was requested at a version other than the root version.
+    NotRoot {
+        package: NoSolutionPackage,
+        version: Version,
+    },
+    /// There are no versions of the package in the given range.
+    NoVersions {
+        package: NoSolutionPackage,
+        range: Range<Version>,
+    },
+    /// The package, in the given range, depends on the dependency in the given range.
+    Dependency {
+        package: NoSolutionPackage,
+        range: Range<Version>,
+        dependency: NoSolutionPackage,
+        dependency_range: Range<Version>,
+    },
+    /// The package, in the given range, is unavailable (e.g., it has no compatible wheels).
+    Unavailable {
+        package: NoSolutionPackage,
+        range: Range<Version>,
+        reason: String,
+    },
+    /// An incompatibility derived from two other incompatibilities.
+    Derived {
+        terms: Vec<NoSolutionTerm>,
+        cause1: Box<NoSolutionTree>,
+        cause2: Box<NoSolutionTree>,
+    },
+}
+
+impl From<&ErrorTree> for NoSolutionTree {
+    fn from(tree: &ErrorTree) -> Self {
+        match tree {
+            DerivationTree::External(External::NotRoot(package, version)) => Self::NotRoot {
+                package: NoSolutionPackage::from(package),
+                version: version.clone(),
+            },
+            DerivationTree::External(External::NoVersions(package, range)) => Self::NoVersions {
+                package: NoSolutionPackage::from(package),
+                range: range.clone(),
+            },
+            DerivationTree::External(External::FromDependencyOf(
+                package,
+                range,
+                dependency,
+                dependency_range,
+            )) => Self::Dependency {
+                package: NoSolutionPackage::from(package),
+                range: range.clone(),
+                dependency: NoSolutionPackage::from(dependency),
+                dependency_range: dependency_range.clone(),
+            },
+            DerivationTree::External(External::Custom(package, range, reason)) => {
+                Self::Unavailable {
+                    package: NoSolutionPackage::from(package),
+                    range: range.clone(),
+                    reason: reason.to_string(),
+                }
+            }
+            DerivationTree::Derived(derived) => Self::Derived {
+                terms: derived
+                    .terms
+                    .iter()
+                    .map(|(package, term)| NoSolutionTerm::new(package, term))
+                    .collect(),
+                cause1: Box::new(Self::from(&*derived.cause1)),
+                cause2: Box::new(Self::from(&*derived.cause2)),
+            },
+        }
+    }
+}
+
+/// A package that appears in a [`NoSolutionTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoSolutionPackage {
+    name: Option<PackageName>,
+    extra: Option<ExtraName>,
+    group: Option<GroupName>,
+    root: bool,
+    python: bool,
+    display: String,
+}
+
+impl NoSolutionPackage {
+    /// The name of the package, if any.
+    ///
+    /// Returns `None` for virtual packages, like the Python interpreter or an unnamed root.
+    pub fn name(&self) -> Option<&PackageName> {
+        self.name.as_ref()
+    }
+
+    /// The extra enabled for the package, if any.
+    pub fn extra(&self) -> Option<&ExtraName> {
+        self.extra.as_ref()
+    }
+
+    /// The dependency group enabled for the package, if any.
+    pub fn group(&self) -> Option<&GroupName> {
+        self.group.as_ref()
+    }
+
+    /// Returns `true` if this package is the root of the resolution (i.e., the set of input
+    /// requirements).
+    pub fn is_root(&self) -> bool {
+        self.root
+    }
+
+    /// Returns `true` if this package represents the Python interpreter.
+    pub fn is_python(&self) -> bool {
+        self.python
+    }
+}
+
+impl From<&PubGrubPackage> for NoSolutionPackage {
+    fn from(package: &PubGrubPackage) -> Self {
+        Self {
+            name: package.name().cloned(),
+            extra: package.extra().cloned(),
+            group: package.dev().cloned(),
+            root: matches!(&**package, PubGrubPackageInner::Root(_)),
+            python: matches!(&**package, PubGrubPackageInner::Python(_)),
+            display: package.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for NoSolutionPackage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.display)
+    }
+}
+
+/// A term in a derived incompatibility of a [`NoSolutionTree`].
+#[derive(Debug, Clone)]
+pub struct NoSolutionTerm {
+    /// The package to which the term applies.
+    pub package: NoSolutionPackage,
+    /// The range of versions covered by the term.
+    pub range: Range<Version>,
+    /// Whether the term is positive (i.e., the package must be in the range) or negative (i.e.,
+    /// the package must not be in the range).
+    pub positive: bool,
+}
+
+impl NoSolutionTerm {
+    fn new(package: &PubGrubPackage, term: &Term<Range<Version>>) -> Self {
+        let (range, positive) = match term {
+            Term::Positive(range) => (range.clone(), true),
+            Term::Negative(range) => (range.clone(), false),
+        };
+        Self {
+            package: NoSolutionPackage::from(package),
+            range,
+            positive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use pubgrub::{DerivationTree, Derived, External, Range};
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+    use uv_pep508::RequirementOrigin;
+    use uv_pypi_types::VerbatimParsedUrl;
+
+    use super::{root_dependencies, ErrorTree, InputRequirement, ResolveError};
+    use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
+    use crate::ExploredPackage;
+
+    #[test]
+    fn timeout_display() {
+        let err = ResolveError::Timeout {
+            timeout: Duration::from_millis(250),
+            packages: vec![],
+        };
+        assert_eq!(err.to_string(), "Resolution did not complete within 250ms");
+
+        let err = ResolveError::Timeout {
+            timeout: Duration::from_secs(90),
+            packages: vec![
+                ExploredPackage {
+                    name: "foo".to_string(),
+                    decisions: 12,
+                    range: Some((
+                        Version::from_str("1.0.0").unwrap(),
+                        Version::from_str("2.3.0").unwrap(),
+                    )),
+                },
+                ExploredPackage {
+                    name: "bar".to_string(),
+                    decisions: 3,
+                    range: None,
+                },
+            ],
+        };
+        assert_eq!(
+            err.to_string(),
+            "Resolution did not complete within 90s; the most explored packages were: `foo` (12 decisions, explored 1.0.0 through 2.3.0), `bar` (3 decisions, no versions selected)"
+        );
+    }
+
+    #[test]
+    fn backtracking_limit_display() {
+        let err = ResolveError::BacktrackingLimit {
+            limit: 100,
+            packages: vec![ExploredPackage {
+                name: "foo".to_string(),
+                decisions: 1,
+                range: Some((
+                    Version::from_str("1.0.0").unwrap(),
+                    Version::from_str("1.0.0").unwrap(),
+                )),
+            }],
+        };
+        assert_eq!(
+            err.to_string(),
+            "Resolution did not complete within 100 decisions; the most explored packages were: `foo` (1 decision, explored 1.0.0)"
+        );
+    }
+
+    fn input(requirement: &str, origin: Option<RequirementOrigin>) -> uv_pypi_types::Requirement {
+        let requirement =
+            uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement).unwrap();
+        uv_pypi_types::Requirement::from(match origin {
+            Some(origin) => requirement.with_origin(origin),
+            None => requirement,
+        })
+    }
+
+    #[test]
+    fn input_requirement_display() {
+        // Paths are displayed relative to the working directory.
+        let origin =
+            RequirementOrigin::File(std::env::current_dir().unwrap().join("constraints.txt"));
+        let constraint = InputRequirement::Constraint(input("numpy<1.20", Some(origin)));
+        assert_eq!(
+            constraint.to_string(),
+            "constraint `numpy<1.20` from `constraints.txt`"
+        );
+
+        let requirement = InputRequirement::Requirement(input("numpy>=2", None));
+        assert_eq!(requirement.to_string(), "requirement `numpy>=2`");
+    }
+
+    #[test]
+    fn origin_hint() {
+        let origin =
+            RequirementOrigin::File(std::env::current_dir().unwrap().join("constraints.txt"));
+        let hint = super::input_origin_hint(&InputRequirement::Override(input(
+            "numpy<1.20",
+            Some(origin.clone()),
+        )))
+        .unwrap();
+        assert!(hint.contains("The override `"), "{hint}");
+        assert!(hint.contains("numpy<1.20"), "{hint}");
+        assert!(hint.contains("constraints.txt"), "{hint}");
+
+        // Requirements are already named in the report, and inputs without a known origin have
+        // nothing to point at.
+        assert!(
+            super::input_origin_hint(&InputRequirement::Requirement(input(
+                "numpy<1.20",
+                Some(origin)
+            )))
+            .is_none()
+        );
+        assert!(
+            super::input_origin_hint(&InputRequirement::Constraint(input("numpy<1.20", None)))
+                .is_none()
+        );
+        assert!(
+            super::input_origin_hint(&InputRequirement::Constraint(input(
+                "numpy<1.20",
+                Some(RequirementOrigin::Workspace)
+            )))
+            .is_none()
+        );
+    }
+
+    fn package(name: &str) -> PubGrubPackage {
+        PubGrubPackage::from(PubGrubPackageInner::Package {
+            name: PackageName::from_str(name).unwrap(),
+            extra: None,
+            dev: None,
+            marker: None,
+        })
+    }
+
+    fn depends_on(package: &PubGrubPackage, dependency: &str, range: &str) -> ErrorTree {
+        DerivationTree::External(External::FromDependencyOf(
+            package.clone(),
+            Range::full(),
+            self::package(dependency),
+            Range::higher_than(Version::from_str(range).unwrap()),
+        ))
+    }
+
+    fn derived(cause1: ErrorTree, cause2: ErrorTree) -> ErrorTree {
+        DerivationTree::Derived(Derived {
+            terms: Default::default(),
+            shared_id: None,
+            cause1: Arc::new(cause1),
+            cause2: Arc::new(cause2),
+        })
+    }
+
+    #[test]
+    fn root_dependencies_project() {
+        // The root depends on the project, which depends on `a` and `b`. `b` depends on `c`,
+        // which isn't an input requirement.
+        let root = PubGrubPackage::from(PubGrubPackageInner::Root(Some(
+            PackageName::from_str("project").unwrap(),
+        )));
+        let project = package("project");
+        let b = package("b");
+        let tree = derived(
+            depends_on(&root, "project", "0"),
+            derived(
+                derived(depends_on(&project, "a", "2"), depends_on(&b, "c", "1")),
+                derived(
+                    depends_on(&project, "b", "1"),
+                    depends_on(&project, "a", "2"),
+                ),
+            ),
+        );
+
+        let workspace_members = BTreeSet::from([PackageName::from_str("project").unwrap()]);
+        let dependencies = root_dependencies(&tree, &workspace_members);
+        assert_eq!(
+            dependencies
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+
+        // Without workspace members, only the root's own dependencies are reported.
+        let dependencies = root_dependencies(&tree, &BTreeSet::new());
+        assert_eq!(
+            dependencies
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["project"]
+        );
+    }
+}
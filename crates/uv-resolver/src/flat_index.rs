@@ -18,6 +18,8 @@ use uv_platform_tags::{TagCompatibility, Tags};
 use uv_pypi_types::HashDigest;
 use uv_types::HashStrategy;
 
+use crate::ExcludeNewer;
+
 /// A set of [`PrioritizedDist`] from a `--find-links` entry, indexed by [`PackageName`]
 /// and [`Version`].
 #[derive(Debug, Clone, Default)]
@@ -210,6 +212,16 @@ impl FlatDistributions {
     pub fn remove(&mut self, version: &Version) -> Option<PrioritizedDist> {
         self.0.remove(version)
     }
+
+    /// Mark any distributions that were uploaded after the given cutoff as incompatible.
+    ///
+    /// As with registry distributions, files without an upload time are excluded too, since they
+    /// can't be shown to predate the cutoff.
+    pub(crate) fn exclude_newer(&mut self, exclude_newer: &ExcludeNewer) {
+        for dist in self.0.values_mut() {
+            dist.exclude_newer(exclude_newer.timestamp_millis());
+        }
+    }
 }
 
 impl IntoIterator for FlatDistributions {
@@ -233,3 +245,66 @@ impl From<BTreeMap<Version, PrioritizedDist>> for FlatDistributions {
         Self(distributions)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use url::Url;
+
+    use uv_distribution_types::FileLocation;
+
+    use super::*;
+
+    /// Create a `--find-links` entry for the given wheel, uploaded at the given time.
+    fn entry(filename: &str, upload_time_utc_ms: Option<i64>) -> (DistFilename, File, IndexUrl) {
+        let url = Url::parse("https://example.com/wheels/").unwrap();
+        let file = File {
+            dist_info_metadata: false,
+            filename: filename.to_string(),
+            hashes: vec![],
+            requires_python: None,
+            size: None,
+            upload_time_utc_ms,
+            url: FileLocation::AbsoluteUrl(url.join(filename).unwrap().into()),
+            yanked: None,
+        };
+        (
+            DistFilename::try_from_normalized_filename(filename).unwrap(),
+            file,
+            IndexUrl::from_str(url.as_str()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn exclude_newer() {
+        let flat_index = FlatIndex::from_entries(
+            FlatIndexEntries {
+                entries: vec![
+                    // 2024-01-01T00:00:00Z
+                    entry("foo-1.0.0-py3-none-any.whl", Some(1_704_067_200_000)),
+                    // 2024-06-01T00:00:00Z
+                    entry("foo-2.0.0-py3-none-any.whl", Some(1_717_200_000_000)),
+                    entry("foo-3.0.0-py3-none-any.whl", None),
+                ],
+                offline: false,
+            },
+            None,
+            &HashStrategy::default(),
+            &BuildOptions::default(),
+        );
+
+        let mut distributions = flat_index
+            .get(&PackageName::from_str("foo").unwrap())
+            .cloned()
+            .unwrap();
+        distributions.exclude_newer(&ExcludeNewer::from_str("2024-03-25T00:00:00Z").unwrap());
+
+        let available = distributions
+            .iter()
+            .filter(|(_, dist)| dist.get().is_some())
+            .map(|(version, _)| version.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(available, ["1.0.0"]);
+    }
+}
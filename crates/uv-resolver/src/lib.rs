@@ -1,5 +1,8 @@
 pub use dependency_mode::DependencyMode;
-pub use error::{NoSolutionError, NoSolutionHeader, ResolveError, SentinelRange};
+pub use error::{
+    InputRequirement, NoSolutionError, NoSolutionHeader, NoSolutionPackage, NoSolutionTerm,
+    NoSolutionTree, ResolveError, SentinelRange,
+};
 pub use exclude_newer::ExcludeNewer;
 pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
@@ -14,13 +17,14 @@ pub use prerelease::PrereleaseMode;
 pub use python_requirement::PythonRequirement;
 pub use requires_python::{RequiresPython, RequiresPythonRange};
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
+    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, PackageDiff,
+    ResolverOutput,
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
-    BuildId, DefaultResolverProvider, DerivationChainBuilder, InMemoryIndex, MetadataResponse,
-    PackageVersionsResult, Reporter as ResolverReporter, Resolver, ResolverEnvironment,
-    ResolverProvider, VersionsResponse, WheelMetadataResult,
+    BuildId, DefaultResolverProvider, DerivationChainBuilder, ExploredPackage, InMemoryIndex,
+    MetadataResponse, PackageVersionsResult, Reporter as ResolverReporter, Resolver,
+    ResolverEnvironment, ResolverProvider, ResolverStats, VersionsResponse, WheelMetadataResult,
 };
 pub use version_map::VersionMap;
 pub use yanks::AllowedYanks;
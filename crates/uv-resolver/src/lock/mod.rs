@@ -233,7 +233,9 @@ impl Lock {
         let options = ResolverOptions {
             resolution_mode: resolution.options.resolution_mode,
             prerelease_mode: resolution.options.prerelease_mode,
+            prerelease_packages: resolution.options.prerelease_packages.clone(),
             exclude_newer: resolution.options.exclude_newer,
+            exclude_newer_packages: resolution.options.exclude_newer_packages.clone(),
         };
         let lock = Self::new(
             VERSION,
@@ -542,11 +544,21 @@ impl Lock {
         self.options.prerelease_mode
     }
 
+    /// Returns the packages for which pre-release versions were allowed when generating this lock.
+    pub fn prerelease_packages(&self) -> &BTreeSet<PackageName> {
+        &self.options.prerelease_packages
+    }
+
     /// Returns the exclude newer setting used to generate this lock.
     pub fn exclude_newer(&self) -> Option<ExcludeNewer> {
         self.options.exclude_newer
     }
 
+    /// Returns the per-package exclude newer settings used to generate this lock.
+    pub fn exclude_newer_packages(&self) -> &BTreeMap<PackageName, ExcludeNewer> {
+        &self.options.exclude_newer_packages
+    }
+
     /// Returns the conflicting groups that were used to generate this lock.
     pub fn conflicts(&self) -> &Conflicts {
         &self.conflicts
@@ -668,9 +680,29 @@ impl Lock {
                     value(self.options.prerelease_mode.to_string()),
                 );
             }
+            if !self.options.prerelease_packages.is_empty() {
+                options_table.insert(
+                    "prerelease-package",
+                    value(
+                        self.options
+                            .prerelease_packages
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Array>(),
+                    ),
+                );
+            }
             if let Some(exclude_newer) = self.options.exclude_newer {
                 options_table.insert("exclude-newer", value(exclude_newer.to_string()));
             }
+            if !self.options.exclude_newer_packages.is_empty() {
+                let mut exclude_newer_packages = Table::new();
+                for (package_name, exclude_newer) in &self.options.exclude_newer_packages {
+                    exclude_newer_packages
+                        .insert(package_name.as_ref(), value(exclude_newer.to_string()));
+                }
+                options_table.insert("exclude-newer-package", Item::Table(exclude_newer_packages));
+            }
 
             if !options_table.is_empty() {
                 doc.insert("options", Item::Table(options_table));
@@ -1310,8 +1342,15 @@ struct ResolverOptions {
     /// The [`PrereleaseMode`] used to generate this lock.
     #[serde(default)]
     prerelease_mode: PrereleaseMode,
+    /// The packages for which pre-release versions were allowed, regardless of the
+    /// [`PrereleaseMode`].
+    #[serde(default, rename = "prerelease-package")]
+    prerelease_packages: BTreeSet<PackageName>,
     /// The [`ExcludeNewer`] used to generate this lock.
     exclude_newer: Option<ExcludeNewer>,
+    /// The per-package [`ExcludeNewer`] cutoffs used to generate this lock.
+    #[serde(default, rename = "exclude-newer-package")]
+    exclude_newer_packages: BTreeMap<PackageName, ExcludeNewer>,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, PartialEq, Eq)]
@@ -4453,4 +4492,47 @@ source = { editable = "path/to/dir" }
         let result: Result<Lock, _> = toml::from_str(data);
         insta::assert_debug_snapshot!(result);
     }
+
+    #[test]
+    fn per_package_options_roundtrip() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+
+[options]
+prerelease-package = ["b", "a"]
+
+[options.exclude-newer-package]
+a = "2024-03-25T00:00:00Z"
+
+[[package]]
+name = "a"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        assert_eq!(
+            lock.prerelease_packages()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+
+        assert_eq!(
+            lock.exclude_newer_packages()
+                .iter()
+                .map(|(package_name, exclude_newer)| format!("{package_name}={exclude_newer}"))
+                .collect::<Vec<_>>(),
+            ["a=2024-03-25T00:00:00Z"]
+        );
+
+        let roundtrip: Lock = toml::from_str(&lock.to_toml().unwrap()).unwrap();
+        assert_eq!(roundtrip.prerelease_packages(), lock.prerelease_packages());
+        assert_eq!(
+            roundtrip.exclude_newer_packages(),
+            lock.exclude_newer_packages()
+        );
+    }
 }
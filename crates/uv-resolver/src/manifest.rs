@@ -52,6 +52,11 @@ pub struct Manifest {
     /// determinations around "allowed" versions (for example, "allowed" URLs or "allowed"
     /// pre-release versions).
     pub(crate) lookaheads: Vec<RequestedRequirements>,
+
+    /// The packages that must not appear in the resolution.
+    ///
+    /// If any requirement would pull in one of these packages, resolution fails.
+    pub(crate) forbidden_packages: BTreeSet<PackageName>,
 }
 
 impl Manifest {
@@ -76,6 +81,7 @@ impl Manifest {
             workspace_members: workspace_members.unwrap_or_default(),
             exclusions,
             lookaheads,
+            forbidden_packages: BTreeSet::new(),
         }
     }
 
@@ -90,6 +96,7 @@ impl Manifest {
             exclusions: Exclusions::default(),
             workspace_members: BTreeSet::new(),
             lookaheads: Vec::new(),
+            forbidden_packages: BTreeSet::new(),
         }
     }
 
@@ -99,6 +106,17 @@ impl Manifest {
         self
     }
 
+    /// Forbid the given packages from appearing in the resolution (e.g., known typosquats or
+    /// deprecated build-time dependencies like `pytest-runner`).
+    #[must_use]
+    pub fn with_forbidden_packages(
+        mut self,
+        forbidden_packages: impl IntoIterator<Item = PackageName>,
+    ) -> Self {
+        self.forbidden_packages = forbidden_packages.into_iter().collect();
+        self
+    }
+
     /// Return an iterator over all requirements, constraints, and overrides, in priority order,
     /// such that requirements come first, followed by constraints, followed by overrides.
     ///
@@ -1,14 +1,19 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use uv_configuration::IndexStrategy;
+use uv_normalize::PackageName;
 
 use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PrereleaseMode,
+    pub prerelease_packages: BTreeSet<PackageName>,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer_packages: BTreeMap<PackageName, ExcludeNewer>,
     pub index_strategy: IndexStrategy,
     pub flexibility: Flexibility,
 }
@@ -18,8 +23,10 @@ pub struct Options {
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prerelease_packages: BTreeSet<PackageName>,
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_packages: BTreeMap<PackageName, ExcludeNewer>,
     index_strategy: IndexStrategy,
     flexibility: Flexibility,
 }
@@ -44,6 +51,17 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages for which pre-release versions are allowed, regardless of the
+    /// [`PrereleaseMode`].
+    #[must_use]
+    pub fn prerelease_packages(
+        mut self,
+        prerelease_packages: impl IntoIterator<Item = PackageName>,
+    ) -> Self {
+        self.prerelease_packages = prerelease_packages.into_iter().collect();
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -58,6 +76,16 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the per-package exclusion dates, which take precedence over the global exclusion date.
+    #[must_use]
+    pub fn exclude_newer_packages(
+        mut self,
+        exclude_newer_packages: impl IntoIterator<Item = (PackageName, ExcludeNewer)>,
+    ) -> Self {
+        self.exclude_newer_packages = exclude_newer_packages.into_iter().collect();
+        self
+    }
+
     /// Sets the index strategy.
     #[must_use]
     pub fn index_strategy(mut self, index_strategy: IndexStrategy) -> Self {
@@ -77,8 +105,10 @@ impl OptionsBuilder {
         Options {
             resolution_mode: self.resolution_mode,
             prerelease_mode: self.prerelease_mode,
+            prerelease_packages: self.prerelease_packages,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
+            exclude_newer_packages: self.exclude_newer_packages,
             index_strategy: self.index_strategy,
             flexibility: self.flexibility,
         }
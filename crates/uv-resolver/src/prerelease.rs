@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use uv_pypi_types::RequirementSource;
 
 use crate::resolver::ForkSet;
@@ -67,16 +69,25 @@ pub(crate) enum PrereleaseStrategy {
 impl PrereleaseStrategy {
     pub(crate) fn from_mode(
         mode: PrereleaseMode,
+        prerelease_packages: &BTreeSet<PackageName>,
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
     ) -> Self {
         let mut packages = ForkSet::default();
 
+        // Packages that were explicitly opted in to pre-releases are allowed them in every fork,
+        // regardless of the global mode.
+        for package_name in prerelease_packages {
+            packages.insert(package_name.clone(), ());
+        }
+
         match mode {
-            PrereleaseMode::Disallow => Self::Disallow,
+            PrereleaseMode::Disallow if prerelease_packages.is_empty() => Self::Disallow,
+            PrereleaseMode::Disallow => Self::Explicit(packages),
             PrereleaseMode::Allow => Self::Allow,
-            PrereleaseMode::IfNecessary => Self::IfNecessary,
+            PrereleaseMode::IfNecessary if prerelease_packages.is_empty() => Self::IfNecessary,
+            PrereleaseMode::IfNecessary => Self::IfNecessaryOrExplicit(packages),
             _ => {
                 for requirement in manifest.requirements(env, dependencies) {
                     let RequirementSource::Registry { specifier, .. } = &requirement.source else {
@@ -143,3 +154,52 @@ pub(crate) enum AllowPrerelease {
     /// Allow pre-release versions if all versions of this package are pre-release.
     IfNecessary,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn allows(mode: PrereleaseMode, package: &str) -> AllowPrerelease {
+        let prerelease_packages = BTreeSet::from([PackageName::from_str("torch").unwrap()]);
+        let env = ResolverEnvironment::universal(vec![]);
+        let strategy = PrereleaseStrategy::from_mode(
+            mode,
+            &prerelease_packages,
+            &Manifest::simple(vec![]),
+            &env,
+            DependencyMode::Transitive,
+        );
+        strategy.allows(&PackageName::from_str(package).unwrap(), &env)
+    }
+
+    #[test]
+    fn prerelease_packages() {
+        assert_eq!(
+            allows(PrereleaseMode::Disallow, "torch"),
+            AllowPrerelease::Yes
+        );
+        assert_eq!(
+            allows(PrereleaseMode::Disallow, "numpy"),
+            AllowPrerelease::No
+        );
+        assert_eq!(
+            allows(PrereleaseMode::IfNecessary, "torch"),
+            AllowPrerelease::Yes
+        );
+        assert_eq!(
+            allows(PrereleaseMode::IfNecessary, "numpy"),
+            AllowPrerelease::IfNecessary
+        );
+        assert_eq!(
+            allows(PrereleaseMode::Explicit, "torch"),
+            AllowPrerelease::Yes
+        );
+        assert_eq!(
+            allows(PrereleaseMode::Explicit, "numpy"),
+            AllowPrerelease::No
+        );
+        assert_eq!(allows(PrereleaseMode::Allow, "numpy"), AllowPrerelease::Yes);
+    }
+}
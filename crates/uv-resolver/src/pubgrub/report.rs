@@ -512,7 +512,7 @@ impl PubGrubReportFormatter<'_> {
         fork_urls: &ForkUrls,
         env: &ResolverEnvironment,
         workspace_members: &BTreeSet<PackageName>,
-        options: Options,
+        options: &Options,
         output_hints: &mut IndexSet<PubGrubHint>,
     ) {
         match derivation_tree {
@@ -661,6 +661,7 @@ impl PubGrubReportFormatter<'_> {
                 });
             }
             Some(UnavailablePackage::NotFound) => {}
+            Some(UnavailablePackage::Forbidden) => {}
             None => {}
         }
 
@@ -0,0 +1,241 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::ResolverOutput;
+
+/// A change to a single package between two resolutions.
+///
+/// Since a universal resolution can include multiple versions of the same package (e.g., in
+/// different forks), each side of a change is a set of versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageDiff {
+    /// The package is only present in the new resolution.
+    Added {
+        name: PackageName,
+        versions: BTreeSet<Version>,
+    },
+    /// The package is only present in the old resolution.
+    Removed {
+        name: PackageName,
+        versions: BTreeSet<Version>,
+    },
+    /// The package is present at a single version in both resolutions, and the new version is
+    /// greater.
+    Upgraded {
+        name: PackageName,
+        from: BTreeSet<Version>,
+        to: BTreeSet<Version>,
+    },
+    /// The package is present at a single version in both resolutions, and the new version is
+    /// lesser.
+    Downgraded {
+        name: PackageName,
+        from: BTreeSet<Version>,
+        to: BTreeSet<Version>,
+    },
+    /// The package is present in both resolutions with different sets of versions, at least one of
+    /// which contains multiple versions, such that the change isn't strictly an upgrade or a
+    /// downgrade.
+    Changed {
+        name: PackageName,
+        from: BTreeSet<Version>,
+        to: BTreeSet<Version>,
+    },
+}
+
+impl PackageDiff {
+    /// Compute the changes required to go from the `old` package versions to the `new` package
+    /// versions, sorted by package name.
+    pub fn compute<'a>(
+        old: impl IntoIterator<Item = (&'a PackageName, &'a Version)>,
+        new: impl IntoIterator<Item = (&'a PackageName, &'a Version)>,
+    ) -> Vec<Self> {
+        fn collect<'a>(
+            packages: impl IntoIterator<Item = (&'a PackageName, &'a Version)>,
+        ) -> BTreeMap<&'a PackageName, BTreeSet<Version>> {
+            let mut versions: BTreeMap<&PackageName, BTreeSet<Version>> = BTreeMap::new();
+            for (name, version) in packages {
+                versions.entry(name).or_default().insert(version.clone());
+            }
+            versions
+        }
+
+        let mut old = collect(old);
+        let mut new = collect(new);
+
+        let names: BTreeSet<&PackageName> = old.keys().chain(new.keys()).copied().collect();
+        names
+            .into_iter()
+            .filter_map(|name| match (old.remove(name), new.remove(name)) {
+                (Some(from), Some(to)) if from == to => None,
+                (Some(from), Some(to)) => {
+                    let name = name.clone();
+                    // Versions can only be ordered if there's exactly one on each side.
+                    let ordering = match (from.iter().next(), to.iter().next()) {
+                        (Some(old), Some(new)) if from.len() == 1 && to.len() == 1 => {
+                            Some(new.cmp(old))
+                        }
+                        _ => None,
+                    };
+                    Some(match ordering {
+                        Some(std::cmp::Ordering::Greater) => Self::Upgraded { name, from, to },
+                        Some(_) => Self::Downgraded { name, from, to },
+                        None => Self::Changed { name, from, to },
+                    })
+                }
+                (Some(versions), None) => Some(Self::Removed {
+                    name: name.clone(),
+                    versions,
+                }),
+                (None, Some(versions)) => Some(Self::Added {
+                    name: name.clone(),
+                    versions,
+                }),
+                (None, None) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the name of the package that changed.
+    pub fn name(&self) -> &PackageName {
+        match self {
+            Self::Added { name, .. }
+            | Self::Removed { name, .. }
+            | Self::Upgraded { name, .. }
+            | Self::Downgraded { name, .. }
+            | Self::Changed { name, .. } => name,
+        }
+    }
+}
+
+impl Display for PackageDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn versions(versions: &BTreeSet<Version>) -> String {
+            versions
+                .iter()
+                .map(|version| format!("v{version}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+
+        match self {
+            Self::Added { name, versions: v } => write!(f, "Added {name} {}", versions(v)),
+            Self::Removed { name, versions: v } => write!(f, "Removed {name} {}", versions(v)),
+            Self::Upgraded { name, from, to } => {
+                write!(f, "Upgraded {name} {} -> {}", versions(from), versions(to))
+            }
+            Self::Downgraded { name, from, to } => {
+                write!(
+                    f,
+                    "Downgraded {name} {} -> {}",
+                    versions(from),
+                    versions(to)
+                )
+            }
+            Self::Changed { name, from, to } => {
+                write!(f, "Changed {name} {} -> {}", versions(from), versions(to))
+            }
+        }
+    }
+}
+
+impl ResolverOutput {
+    /// Compute the changes required to go from this resolution to `other`.
+    ///
+    /// See [`PackageDiff::compute`].
+    pub fn diff(&self, other: &ResolverOutput) -> Vec<PackageDiff> {
+        fn versions(output: &ResolverOutput) -> impl Iterator<Item = (&PackageName, &Version)> {
+            output
+                .dists()
+                .filter(|dist| dist.is_base())
+                .map(|dist| (&dist.name, &dist.version))
+        }
+
+        PackageDiff::compute(versions(self), versions(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::PackageDiff;
+
+    fn packages(packages: &[(&str, &str)]) -> Vec<(PackageName, Version)> {
+        packages
+            .iter()
+            .map(|(name, version)| {
+                (
+                    PackageName::from_str(name).unwrap(),
+                    Version::from_str(version).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn diff(old: &[(&str, &str)], new: &[(&str, &str)]) -> Vec<String> {
+        let old = packages(old);
+        let new = packages(new);
+        PackageDiff::compute(
+            old.iter().map(|(name, version)| (name, version)),
+            new.iter().map(|(name, version)| (name, version)),
+        )
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    #[test]
+    fn single_versions() {
+        assert_eq!(
+            diff(
+                &[
+                    ("a", "1.0.0"),
+                    ("b", "2.0.0"),
+                    ("c", "1.0.0"),
+                    ("d", "1.0.0")
+                ],
+                &[
+                    ("a", "1.0.0"),
+                    ("b", "1.0.0"),
+                    ("c", "1.10.0"),
+                    ("e", "1.0.0")
+                ],
+            ),
+            [
+                "Downgraded b v2.0.0 -> v1.0.0",
+                "Upgraded c v1.0.0 -> v1.10.0",
+                "Removed d v1.0.0",
+                "Added e v1.0.0",
+            ]
+        );
+    }
+
+    #[test]
+    fn multiple_versions() {
+        // Comparing the sets lexicographically would call this a downgrade, even though one of the
+        // versions increased.
+        assert_eq!(
+            diff(
+                &[("a", "1.0.0"), ("a", "3.0.0")],
+                &[("a", "1.0.0"), ("a", "2.0.0"), ("a", "4.0.0")],
+            ),
+            ["Changed a v1.0.0, v3.0.0 -> v1.0.0, v2.0.0, v4.0.0"]
+        );
+        assert_eq!(
+            diff(&[("a", "1.0.0")], &[("a", "1.0.0"), ("a", "2.0.0")]),
+            ["Changed a v1.0.0 -> v1.0.0, v2.0.0"]
+        );
+        assert!(diff(
+            &[("a", "1.0.0"), ("a", "2.0.0")],
+            &[("a", "2.0.0"), ("a", "1.0.0")]
+        )
+        .is_empty());
+    }
+}
@@ -10,11 +10,13 @@ use uv_pep440::Version;
 use uv_pep508::MarkerTree;
 use uv_pypi_types::HashDigest;
 
+pub use crate::resolution::diff::PackageDiff;
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
 pub(crate) use crate::resolution::output::ResolutionGraphNode;
 pub use crate::resolution::output::{ConflictingDistributionError, ResolverOutput};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 
+mod diff;
 mod display;
 mod output;
 mod requirements_txt;
@@ -1,4 +1,5 @@
-use std::collections::BTreeMap;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::{Display, Formatter};
 
 use indexmap::IndexSet;
@@ -30,7 +31,7 @@ use crate::resolution_mode::ResolutionStrategy;
 use crate::resolver::{Resolution, ResolutionDependencyEdge, ResolutionPackage};
 use crate::{
     InMemoryIndex, MetadataResponse, Options, PythonRequirement, RequiresPython, ResolveError,
-    VersionsResponse,
+    ResolverStats, VersionsResponse,
 };
 
 pub(crate) type MarkersForDistribution = Vec<MarkerTree>;
@@ -58,6 +59,8 @@ pub struct ResolverOutput {
     pub(crate) overrides: Overrides,
     /// The options that were used to build the graph.
     pub(crate) options: Options,
+    /// Statistics about the resolution that produced the graph.
+    pub(crate) stats: ResolverStats,
 }
 
 #[derive(Debug, Clone)]
@@ -108,6 +111,7 @@ impl ResolverOutput {
         conflicts: &Conflicts,
         resolution_strategy: &ResolutionStrategy,
         options: Options,
+        stats: ResolverStats,
     ) -> Result<Self, ResolveError> {
         let size_guess = resolutions[0].nodes.len();
         let mut graph: Graph<ResolutionGraphNode, MarkerTree, Directed> =
@@ -234,6 +238,7 @@ impl ResolverOutput {
             overrides: overrides.clone(),
             options,
             fork_markers,
+            stats,
         };
 
         // We only do conflicting distribution detection when no
@@ -561,7 +566,7 @@ impl ResolverOutput {
     }
 
     /// Returns an iterator over the distinct packages in the graph.
-    fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
+    pub(crate) fn dists(&self) -> impl Iterator<Item = &AnnotatedDist> {
         self.graph
             .node_indices()
             .filter_map(move |index| match &self.graph[index] {
@@ -585,6 +590,29 @@ impl ResolverOutput {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Return the chains of dependencies through which the given package was included in the
+    /// resolution (i.e., "why is this package installed?").
+    ///
+    /// Returns one chain per direct requirement through which the package is reachable: the
+    /// shortest path from that requirement to the given package. Extras and dependency groups are
+    /// collapsed into their base package, such that consecutive elements of a chain are always
+    /// distinct. Returns an empty list if the package is not in the graph.
+    pub fn why(&self, name: &PackageName) -> Vec<Vec<PackageName>> {
+        shortest_chains(
+            &self.graph,
+            |node| match node {
+                ResolutionGraphNode::Root => None,
+                ResolutionGraphNode::Dist(dist) => Some(&dist.name),
+            },
+            name,
+        )
+    }
+
+    /// Return the [`ResolverStats`] for the resolution that produced the graph.
+    pub fn stats(&self) -> &ResolverStats {
+        &self.stats
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
@@ -971,3 +999,135 @@ fn has_lower_bound(
     }
     false
 }
+
+/// Return, for each direct dependency through which the package `name` is reachable, the shortest
+/// chain of packages from that dependency to `name`.
+///
+/// Nodes for which `package_name` returns `None` are treated as the root. Rather than enumerating
+/// every path (which is exponential in the worst case), this performs a single breadth-first search
+/// backwards from the matching nodes.
+fn shortest_chains<'graph, N, E>(
+    graph: &'graph Graph<N, E, Directed>,
+    package_name: impl Fn(&'graph N) -> Option<&'graph PackageName>,
+    name: &PackageName,
+) -> Vec<Vec<PackageName>> {
+    // For each visited node, the next node along a shortest path to the package.
+    let mut next: FxHashMap<NodeIndex, Option<NodeIndex>> = FxHashMap::default();
+    let mut queue = VecDeque::new();
+    for index in graph.node_indices() {
+        if package_name(&graph[index]) == Some(name) {
+            next.insert(index, None);
+            queue.push_back(index);
+        }
+    }
+
+    let mut chains = BTreeSet::new();
+    while let Some(index) = queue.pop_front() {
+        for parent in graph.neighbors_directed(index, Direction::Incoming) {
+            if package_name(&graph[parent]).is_none() {
+                // The node is a direct dependency, so follow the path forwards to the package.
+                let mut chain = Vec::new();
+                let mut current = Some(index);
+                while let Some(node) = current {
+                    chain.extend(package_name(&graph[node]).cloned());
+                    current = next[&node];
+                }
+                chain.dedup();
+                chains.insert(chain);
+            } else if let Entry::Vacant(entry) = next.entry(parent) {
+                entry.insert(Some(index));
+                queue.push_back(parent);
+            }
+        }
+    }
+    chains.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use petgraph::graph::{Graph, NodeIndex};
+
+    use uv_normalize::PackageName;
+
+    use super::shortest_chains;
+
+    /// Build a graph from the given edges, where `None` is the root.
+    fn graph(edges: &[(Option<&str>, &str)]) -> Graph<Option<PackageName>, ()> {
+        let mut graph = Graph::new();
+        let mut nodes = std::collections::BTreeMap::<Option<&str>, NodeIndex>::new();
+        for (from, to) in edges {
+            for name in [*from, Some(*to)] {
+                nodes.entry(name).or_insert_with(|| {
+                    graph.add_node(name.map(|name| PackageName::from_str(name).unwrap()))
+                });
+            }
+            graph.add_edge(nodes[from], nodes[&Some(*to)], ());
+        }
+        graph
+    }
+
+    fn why(graph: &Graph<Option<PackageName>, ()>, name: &str) -> Vec<Vec<String>> {
+        shortest_chains(graph, Option::as_ref, &PackageName::from_str(name).unwrap())
+            .into_iter()
+            .map(|chain| chain.iter().map(ToString::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn chain() {
+        let graph = graph(&[(None, "a"), (Some("a"), "b"), (Some("b"), "c")]);
+        assert_eq!(why(&graph, "c"), [["a", "b", "c"]]);
+        assert_eq!(why(&graph, "a"), [["a"]]);
+        assert!(why(&graph, "d").is_empty());
+    }
+
+    #[test]
+    fn shortest_per_direct_dependency() {
+        // `a` reaches `d` via both `b` and `b -> c`; only the shorter path is reported. `e` is a
+        // second direct dependency with its own path.
+        let graph = graph(&[
+            (None, "a"),
+            (None, "e"),
+            (Some("a"), "b"),
+            (Some("b"), "c"),
+            (Some("c"), "d"),
+            (Some("b"), "d"),
+            (Some("e"), "d"),
+        ]);
+        assert_eq!(why(&graph, "d"), [vec!["a", "b", "d"], vec!["e", "d"]]);
+    }
+
+    #[test]
+    fn cycle() {
+        let graph = graph(&[
+            (None, "a"),
+            (Some("a"), "b"),
+            (Some("b"), "c"),
+            (Some("c"), "a"),
+        ]);
+        assert_eq!(why(&graph, "c"), [["a", "b", "c"]]);
+    }
+
+    #[test]
+    fn wide() {
+        // A layered graph with 2^20 distinct paths from the root to the package.
+        let names = (0..=20)
+            .map(|layer| (format!("l{layer}a"), format!("l{layer}b")))
+            .collect::<Vec<_>>();
+        let mut edges = vec![(None, "l0a"), (None, "l0b")];
+        for window in names.windows(2) {
+            let (from, to) = (&window[0], &window[1]);
+            for from in [&from.0, &from.1] {
+                for to in [&to.0, &to.1] {
+                    edges.push((Some(from.as_str()), to.as_str()));
+                }
+            }
+        }
+        let graph = graph(&edges);
+        let chains = why(&graph, "l20a");
+        assert_eq!(chains.len(), 2);
+        assert!(chains.iter().all(|chain| chain.len() == 21));
+    }
+}
@@ -106,6 +106,8 @@ pub(crate) enum UnavailablePackage {
     InvalidMetadata(String),
     /// The package has an invalid structure.
     InvalidStructure(String),
+    /// The package was explicitly forbidden from the resolution.
+    Forbidden,
 }
 
 impl UnavailablePackage {
@@ -117,6 +119,7 @@ impl UnavailablePackage {
             UnavailablePackage::MissingMetadata => "not include a `METADATA` file",
             UnavailablePackage::InvalidMetadata(_) => "invalid metadata",
             UnavailablePackage::InvalidStructure(_) => "an invalid package format",
+            UnavailablePackage::Forbidden => "forbidden",
         }
     }
 
@@ -128,6 +131,7 @@ impl UnavailablePackage {
             UnavailablePackage::MissingMetadata => format!("does {self}"),
             UnavailablePackage::InvalidMetadata(_) => format!("has {self}"),
             UnavailablePackage::InvalidStructure(_) => format!("has {self}"),
+            UnavailablePackage::Forbidden => format!("is {self}"),
         }
     }
 }
@@ -253,6 +253,11 @@ impl BatchPrefetcher {
         (num_tried, do_prefetch)
     }
 
+    /// Return the total number of versions tried, across all packages.
+    pub(crate) fn total_tried_versions(&self) -> usize {
+        self.tried_versions.values().sum()
+    }
+
     /// Log stats about how many versions we tried.
     ///
     /// Note that they may be inflated when we count the same version repeatedly during
@@ -0,0 +1,159 @@
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use uv_pep440::Version;
+
+use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
+
+/// A package that the solver made many decisions on, reported when resolution is aborted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExploredPackage {
+    /// The name of the package.
+    pub name: String,
+    /// The number of times the solver picked the package to decide on a version, including
+    /// decisions for which no compatible version was found.
+    pub decisions: usize,
+    /// The lowest and highest version selected for the package, if any.
+    pub range: Option<(Version, Version)>,
+}
+
+impl Display for ExploredPackage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let decisions = if self.decisions == 1 {
+            "1 decision".to_string()
+        } else {
+            format!("{} decisions", self.decisions)
+        };
+        match &self.range {
+            None => write!(f, "`{}` ({decisions}, no versions selected)", self.name),
+            Some((lowest, highest)) if lowest == highest => {
+                write!(f, "`{}` ({decisions}, explored {lowest})", self.name)
+            }
+            Some((lowest, highest)) => write!(
+                f,
+                "`{}` ({decisions}, explored {lowest} through {highest})",
+                self.name
+            ),
+        }
+    }
+}
+
+/// Tracks the decisions made by the solver, across all forks, to bound the amount of
+/// backtracking and to report where the solver spent its effort.
+#[derive(Debug, Default)]
+pub(crate) struct ExploredVersions {
+    packages: FxHashMap<PubGrubPackage, (usize, Option<(Version, Version)>)>,
+    decisions: usize,
+}
+
+impl ExploredVersions {
+    /// Record a decision on `package`, along with the selected version, if any.
+    pub(crate) fn decision(&mut self, package: &PubGrubPackage, version: Option<&Version>) {
+        self.decisions += 1;
+
+        // Only track base packages, no virtual packages from extras.
+        if !matches!(
+            &**package,
+            PubGrubPackageInner::Package {
+                extra: None,
+                dev: None,
+                marker: None,
+                ..
+            }
+        ) {
+            return;
+        }
+
+        let (decisions, range) = self.packages.entry(package.clone()).or_default();
+        *decisions += 1;
+        if let Some(version) = version {
+            match range {
+                None => *range = Some((version.clone(), version.clone())),
+                Some((lowest, highest)) => {
+                    if version < lowest {
+                        *lowest = version.clone();
+                    }
+                    if version > highest {
+                        *highest = version.clone();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Return the total number of decisions made.
+    pub(crate) fn decisions(&self) -> usize {
+        self.decisions
+    }
+
+    /// Return the `limit` packages with the most decisions.
+    pub(crate) fn most_explored(&self, limit: usize) -> Vec<ExploredPackage> {
+        self.packages
+            .iter()
+            .map(|(package, (decisions, range))| ExploredPackage {
+                name: package.to_string(),
+                decisions: *decisions,
+                range: range.clone(),
+            })
+            .sorted_by(|p1, p2| {
+                p1.decisions
+                    .cmp(&p2.decisions)
+                    .reverse()
+                    .then_with(|| p1.name.cmp(&p2.name))
+            })
+            .take(limit)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::{ExtraName, PackageName};
+
+    use super::*;
+
+    fn package(name: &str, extra: Option<&str>) -> PubGrubPackage {
+        PubGrubPackage::from(PubGrubPackageInner::Package {
+            name: PackageName::from_str(name).unwrap(),
+            extra: extra.map(|extra| ExtraName::from_str(extra).unwrap()),
+            dev: None,
+            marker: None,
+        })
+    }
+
+    fn version(version: &str) -> Version {
+        Version::from_str(version).unwrap()
+    }
+
+    #[test]
+    fn most_explored() {
+        let mut explored = ExploredVersions::default();
+        for v in ["2.0.0", "1.5.0", "3.1.0"] {
+            explored.decision(&package("foo", None), Some(&version(v)));
+        }
+        explored.decision(&package("foo", None), None);
+        explored.decision(&package("bar", None), Some(&version("1.0.0")));
+        explored.decision(&package("baz", None), None);
+        explored.decision(&package("foo", Some("extra")), Some(&version("9.0.0")));
+
+        // Decisions on virtual packages count towards the total, but aren't reported.
+        assert_eq!(explored.decisions(), 7);
+        assert_eq!(
+            explored
+                .most_explored(5)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "`foo` (4 decisions, explored 1.5.0 through 3.1.0)",
+                "`bar` (1 decision, explored 1.0.0)",
+                "`baz` (1 decision, no versions selected)",
+            ]
+        );
+        assert_eq!(explored.most_explored(1).len(), 1);
+    }
+}
@@ -38,6 +38,16 @@ impl<T> ForkMap<T> {
             .push(entry);
     }
 
+    /// Associate a value with a package in all forks.
+    pub(crate) fn insert(&mut self, package_name: PackageName, value: T) {
+        let entry = Entry {
+            value,
+            marker: MarkerTree::TRUE,
+        };
+
+        self.0.entry(package_name).or_default().push(entry);
+    }
+
     /// Returns `true` if the map contains any values for a package that are compatible with the
     /// given fork.
     pub(crate) fn contains(&self, package_name: &PackageName, env: &ResolverEnvironment) -> bool {
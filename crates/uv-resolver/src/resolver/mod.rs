@@ -4,20 +4,23 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::{Display, Formatter, Write};
+use std::hash::Hash;
 use std::ops::Bound;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::{iter, thread};
 
 use dashmap::DashMap;
 use either::Either;
 use futures::{FutureExt, StreamExt};
 use itertools::Itertools;
-use pubgrub::{Incompatibility, Range, State};
+use pubgrub::{Incompatibility, Range, State, Term};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tokio::sync::mpsc::{self, Receiver, Sender};
 use tokio::sync::oneshot;
 use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, instrument, trace, warn, Level};
 
 use environment::ForkingPossibility;
@@ -45,7 +48,7 @@ use uv_warnings::warn_user_once;
 
 use crate::candidate_selector::{CandidateDist, CandidateSelector};
 use crate::dependency_provider::UvDependencyProvider;
-use crate::error::{NoSolutionError, ResolveError};
+use crate::error::{InputRequirement, NoSolutionError, ResolveError};
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_urls::ForkUrls;
 use crate::manifest::Manifest;
@@ -63,8 +66,11 @@ pub(crate) use crate::resolver::availability::{
 };
 use crate::resolver::batch_prefetch::BatchPrefetcher;
 pub use crate::resolver::derivation::DerivationChainBuilder;
+pub use crate::resolver::explored::ExploredPackage;
+use crate::resolver::explored::ExploredVersions;
 
 use crate::resolver::groups::Groups;
+use crate::resolver::index::FxOnceMap;
 pub use crate::resolver::index::InMemoryIndex;
 use crate::resolver::indexes::Indexes;
 pub use crate::resolver::provider::{
@@ -73,6 +79,7 @@ pub use crate::resolver::provider::{
 };
 use crate::resolver::reporter::Facade;
 pub use crate::resolver::reporter::{BuildId, Reporter};
+pub use crate::resolver::stats::ResolverStats;
 use crate::yanks::AllowedYanks;
 use crate::{marker, DependencyMode, Exclusions, FlatIndex, Options, ResolutionMode};
 
@@ -80,12 +87,14 @@ mod availability;
 mod batch_prefetch;
 mod derivation;
 mod environment;
+mod explored;
 mod fork_map;
 mod groups;
 mod index;
 mod indexes;
 mod provider;
 mod reporter;
+mod stats;
 mod urls;
 
 pub struct Resolver<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider> {
@@ -106,6 +115,7 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     capabilities: IndexCapabilities,
     locations: IndexLocations,
     exclusions: Exclusions,
+    forbidden_packages: BTreeSet<PackageName>,
     urls: Urls,
     indexes: Indexes,
     dependency_mode: DependencyMode,
@@ -125,6 +135,24 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     options: Options,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
+    /// The maximum amount of time to spend solving, if any.
+    timeout: Option<Duration>,
+    /// The point in time at which the solver gives up, if any; set when resolution starts.
+    deadline: Option<Instant>,
+    /// A token with which to abort the solver on demand, if any.
+    cancellation: Option<CancellationToken>,
+    /// The maximum number of decisions the solver may make, if any.
+    max_decisions: Option<usize>,
+    /// The number of packages for which versions were requested.
+    packages_fetched: AtomicUsize,
+    /// The number of distributions for which metadata was requested.
+    metadata_fetched: AtomicUsize,
+    /// The number of source distributions built by the provider.
+    builds: Arc<AtomicUsize>,
+    /// The number of source distributions whose metadata the provider read from the cache.
+    metadata_cache_hits: Arc<AtomicUsize>,
+    /// The time the solver spent blocked on requests.
+    wait_time: Mutex<Duration>,
 }
 
 impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
@@ -172,7 +200,8 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             options.exclude_newer,
             build_context.build_options(),
             build_context.capabilities(),
-        );
+        )
+        .with_exclude_newer_packages(options.exclude_newer_packages.clone());
 
         Self::new_custom_io(
             manifest,
@@ -213,7 +242,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             index: index.clone(),
             git: git.clone(),
             capabilities: capabilities.clone(),
-            selector: CandidateSelector::for_resolution(options, &manifest, &env),
+            selector: CandidateSelector::for_resolution(&options, &manifest, &env),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, &env, git, options.dependency_mode)?,
             indexes: Indexes::from_manifest(&manifest, &env, options.dependency_mode),
@@ -225,6 +254,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             overrides: manifest.overrides,
             preferences: manifest.preferences,
             exclusions: manifest.exclusions,
+            forbidden_packages: manifest.forbidden_packages,
             hasher: hasher.clone(),
             locations: locations.clone(),
             env,
@@ -235,27 +265,91 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             incomplete_packages: DashMap::default(),
             options,
             reporter: None,
+            timeout: None,
+            deadline: None,
+            cancellation: None,
+            max_decisions: None,
+            packages_fetched: AtomicUsize::new(0),
+            metadata_fetched: AtomicUsize::new(0),
+            builds: Arc::new(AtomicUsize::new(0)),
+            metadata_cache_hits: Arc::new(AtomicUsize::new(0)),
+            wait_time: Mutex::new(Duration::ZERO),
         };
+        let provider = provider.with_reporter(state.facade(None));
         Ok(Self { state, provider })
     }
 
     /// Set the [`Reporter`] to use for this installer.
     #[must_use]
     pub fn with_reporter(self, reporter: impl Reporter + 'static) -> Self {
-        let reporter = Arc::new(reporter);
+        let reporter: Arc<dyn Reporter> = Arc::new(reporter);
+        let facade = self.state.facade(Some(reporter.clone()));
+
+        Self {
+            state: ResolverState {
+                reporter: Some(reporter),
+                ..self.state
+            },
+            provider: self.provider.with_reporter(facade),
+        }
+    }
+
+    /// Set the maximum amount of time to spend solving.
+    ///
+    /// The deadline is enforced both between solver steps and while waiting on metadata requests.
+    /// If it's exceeded, resolution fails with [`ResolveError::Timeout`], which includes the
+    /// packages that were tried most often up to that point.
+    #[must_use]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self {
+            state: ResolverState {
+                timeout: Some(timeout),
+                ..self.state
+            },
+            provider: self.provider,
+        }
+    }
 
+    /// Set a [`CancellationToken`] with which to abort resolution on demand.
+    ///
+    /// Cancellation is observed at the same points as the deadline set by
+    /// [`Resolver::with_timeout`]. Once the token is cancelled, resolution fails with
+    /// [`ResolveError::Cancelled`], which includes the packages that were tried most often up to
+    /// that point.
+    #[must_use]
+    pub fn with_cancellation(self, cancellation: CancellationToken) -> Self {
         Self {
             state: ResolverState {
-                reporter: Some(reporter.clone()),
+                cancellation: Some(cancellation),
                 ..self.state
             },
-            provider: self.provider.with_reporter(Facade { reporter }),
+            provider: self.provider,
+        }
+    }
+
+    /// Set the maximum number of decisions the solver may make, across all forks.
+    ///
+    /// Each time the solver picks a package to decide on a version counts as a decision, whether
+    /// or not a compatible version is found, so this bounds the amount of backtracking on
+    /// adversarial dependency graphs. If the budget is exceeded, resolution fails with
+    /// [`ResolveError::BacktrackingLimit`], which includes the packages the solver made the most
+    /// decisions on and the range of versions it explored for each.
+    #[must_use]
+    pub fn with_max_decisions(self, max_decisions: usize) -> Self {
+        Self {
+            state: ResolverState {
+                max_decisions: Some(max_decisions),
+                ..self.state
+            },
+            provider: self.provider,
         }
     }
 
     /// Resolve a set of requirements into a set of pinned versions.
     pub async fn resolve(self) -> Result<ResolverOutput, ResolveError> {
-        let state = Arc::new(self.state);
+        let mut state = self.state;
+        state.deadline = state.timeout.map(|timeout| Instant::now() + timeout);
+        let state = Arc::new(state);
         let provider = Arc::new(self.provider);
 
         // A channel to fetch package metadata (e.g., given `flask`, fetch all versions) and version
@@ -305,9 +399,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         );
 
         let mut visited = FxHashSet::default();
+        let mut backtracks = 0;
+        let solve_start = Instant::now();
 
         let root = PubGrubPackage::from(PubGrubPackageInner::Root(self.project.clone()));
         let mut prefetcher = BatchPrefetcher::default();
+        let mut explored = ExploredVersions::default();
         let state = ForkState::new(
             State::init(root.clone(), MIN_VERSION.clone()),
             root,
@@ -325,6 +422,21 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
             let start = Instant::now();
             loop {
+                // Abort if we've exceeded the time budget, or if we've been cancelled.
+                if let (Some(timeout), Some(deadline)) = (self.timeout, self.deadline) {
+                    if Instant::now() >= deadline {
+                        return Err(ResolveError::Timeout {
+                            timeout,
+                            packages: explored.most_explored(5),
+                        });
+                    }
+                }
+                if self.is_cancelled() {
+                    return Err(ResolveError::Cancelled {
+                        packages: explored.most_explored(5),
+                    });
+                }
+
                 // Run unit propagation.
                 if let Err(err) = state.pubgrub.unit_propagation(state.next.clone()) {
                     return Err(self.convert_no_solution_err(
@@ -344,6 +456,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         state.pubgrub.partial_solution.prioritized_packages(),
                         &self.urls,
                         &self.indexes,
+                        &self.forbidden_packages,
                         &state.python_requirement,
                         &request_sink,
                     )?;
@@ -392,6 +505,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 };
 
                 state.next = highest_priority_pkg;
+                if state.decided.contains(&state.next) {
+                    backtracks += 1;
+                }
 
                 let url = state.next.name().and_then(|name| state.fork_urls.get(name));
                 let index = state
@@ -419,18 +535,44 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     .partial_solution
                     .term_intersection_for_package(&state.next)
                     .expect("a package was chosen but we don't have a term");
-                let decision = self.choose_version(
+                let decision = self
+                    .choose_version(
+                        &state.next,
+                        index,
+                        term_intersection.unwrap_positive(),
+                        &mut state.pins,
+                        &preferences,
+                        &state.fork_urls,
+                        &state.env,
+                        &state.python_requirement,
+                        &mut visited,
+                        &request_sink,
+                    )
+                    .map_err(|err| Self::with_most_explored(err, &explored))?;
+
+                if let Some(
+                    ResolverVersion::Available(version) | ResolverVersion::Unavailable(version, _),
+                ) = &decision
+                {
+                    self.on_try_version(&state.next, version);
+                }
+
+                // Abort if we've exceeded the backtracking budget.
+                explored.decision(
                     &state.next,
-                    index,
-                    term_intersection.unwrap_positive(),
-                    &mut state.pins,
-                    &preferences,
-                    &state.fork_urls,
-                    &state.env,
-                    &state.python_requirement,
-                    &mut visited,
-                    &request_sink,
-                )?;
+                    match &decision {
+                        Some(ResolverVersion::Available(version)) => Some(version),
+                        _ => None,
+                    },
+                );
+                if let Some(limit) = self.max_decisions {
+                    if explored.decisions() > limit {
+                        return Err(ResolveError::BacktrackingLimit {
+                            limit,
+                            packages: explored.most_explored(5),
+                        });
+                    }
+                }
 
                 // Pick the next compatible version.
                 let version = match decision {
@@ -509,18 +651,21 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         .pubgrub
                         .partial_solution
                         .add_decision(state.next.clone(), version);
+                    state.decided.insert(state.next.clone());
                     continue;
                 }
 
                 // Retrieve that package dependencies.
-                let forked_deps = self.get_dependencies_forking(
-                    &state.next,
-                    &version,
-                    &state.fork_urls,
-                    &state.env,
-                    &state.python_requirement,
-                    &state.pubgrub,
-                )?;
+                let forked_deps = self
+                    .get_dependencies_forking(
+                        &state.next,
+                        &version,
+                        &state.fork_urls,
+                        &state.env,
+                        &state.python_requirement,
+                        &state.pubgrub,
+                    )
+                    .map_err(|err| Self::with_most_explored(err, &explored))?;
                 match forked_deps {
                     ForkedDependencies::Unavailable(reason) => {
                         // Then here, if we get a reason that we consider unrecoverable, we should
@@ -598,7 +743,18 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         for resolution in &resolutions {
             Self::trace_resolution(resolution);
         }
-        ResolverOutput::from_state(
+        let stats = ResolverStats {
+            versions_tried: prefetcher.total_tried_versions(),
+            packages_fetched: self.packages_fetched.load(AtomicOrdering::Relaxed),
+            metadata_fetched: self.metadata_fetched.load(AtomicOrdering::Relaxed),
+            metadata_cache_hits: self.metadata_cache_hits.load(AtomicOrdering::Relaxed),
+            builds: self.builds.load(AtomicOrdering::Relaxed),
+            backtracks,
+            forks: resolutions.len(),
+            solve_time: solve_start.elapsed(),
+            wait_time: *self.wait_time.lock().unwrap(),
+        };
+        let output = ResolverOutput::from_state(
             &resolutions,
             &self.requirements,
             &self.constraints,
@@ -609,8 +765,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.python_requirement,
             &self.conflicts,
             self.selector.resolution_strategy(),
-            self.options,
-        )
+            self.options.clone(),
+            stats,
+        )?;
+        Ok(output)
     }
 
     /// When trace level logging is enabled, we dump the final
@@ -762,6 +920,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             return Ok(());
         };
 
+        // Forbidden packages can never be selected, so there's no need to fetch their metadata.
+        if self.forbidden_packages.contains(name) {
+            return Ok(());
+        }
+
         if let Some(url) = url {
             // Verify that the package is allowed under the hash-checking policy.
             if !self.hasher.allows_url(&url.verbatim) {
@@ -797,6 +960,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         packages: impl Iterator<Item = (&'data PubGrubPackage, &'data Range<Version>)>,
         urls: &Urls,
         indexes: &Indexes,
+        forbidden_packages: &BTreeSet<PackageName>,
         python_requirement: &PythonRequirement,
         request_sink: &Sender<Request>,
     ) -> Result<(), ResolveError> {
@@ -821,6 +985,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             if indexes.contains_key(name) {
                 continue;
             }
+            // Avoid visiting packages that can never be selected.
+            if forbidden_packages.contains(name) {
+                continue;
+            }
             request_sink.blocking_send(Request::Prefetch(
                 name.clone(),
                 range.clone(),
@@ -830,6 +998,84 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         Ok(())
     }
 
+    /// Wait for the result of an in-flight request, failing with [`ResolveError::Timeout`] if the
+    /// time budget runs out first, or with [`ResolveError::Cancelled`] if resolution is cancelled
+    /// first.
+    fn wait_blocking<K: Eq + Hash, V: Clone>(
+        &self,
+        map: &FxOnceMap<K, V>,
+        key: &K,
+    ) -> Result<Option<V>, ResolveError> {
+        let start = Instant::now();
+        let result = self.wait_blocking_interruptible(map, key);
+        *self.wait_time.lock().unwrap() += start.elapsed();
+        result
+    }
+
+    /// Wait for the result of an in-flight request, giving up once the time budget runs out or
+    /// resolution is cancelled.
+    fn wait_blocking_interruptible<K: Eq + Hash, V: Clone>(
+        &self,
+        map: &FxOnceMap<K, V>,
+        key: &K,
+    ) -> Result<Option<V>, ResolveError> {
+        if self.deadline.is_none() && self.cancellation.is_none() {
+            return Ok(map.wait_blocking(key));
+        }
+        let cancelled = async {
+            match &self.cancellation {
+                Some(cancellation) => cancellation.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+        map.wait_blocking_until(key, self.deadline, cancelled)
+            .map_err(|_| {
+                if self.is_cancelled() {
+                    ResolveError::Cancelled {
+                        packages: Vec::new(),
+                    }
+                } else {
+                    ResolveError::Timeout {
+                        timeout: self.timeout.unwrap_or_default(),
+                        packages: Vec::new(),
+                    }
+                }
+            })
+    }
+
+    /// Create a [`Facade`] through which the provider reports to the given [`Reporter`], and
+    /// counts towards the [`ResolverStats`].
+    fn facade(&self, reporter: Option<Arc<dyn Reporter>>) -> Facade {
+        Facade {
+            reporter,
+            builds: self.builds.clone(),
+            metadata_cache_hits: self.metadata_cache_hits.clone(),
+        }
+    }
+
+    /// Returns `true` if resolution was cancelled via the [`CancellationToken`].
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Attach the most explored packages to a [`ResolveError::Timeout`] or
+    /// [`ResolveError::Cancelled`] raised while waiting on a request, where the
+    /// [`ExploredVersions`] aren't available.
+    fn with_most_explored(err: ResolveError, explored: &ExploredVersions) -> ResolveError {
+        match err {
+            ResolveError::Timeout { timeout, .. } => ResolveError::Timeout {
+                timeout,
+                packages: explored.most_explored(5),
+            },
+            ResolveError::Cancelled { .. } => ResolveError::Cancelled {
+                packages: explored.most_explored(5),
+            },
+            err => err,
+        }
+    }
+
     /// Given a candidate package, choose the next version in range to try.
     ///
     /// Returns `None` when there are no versions in the given range, rejecting the current partial
@@ -864,6 +1110,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             | PubGrubPackageInner::Extra { name, .. }
             | PubGrubPackageInner::Dev { name, .. }
             | PubGrubPackageInner::Package { name, .. } => {
+                if self.forbidden_packages.contains(name) {
+                    debug!("Package `{name}` is forbidden");
+                    self.unavailable_packages
+                        .insert(name.clone(), UnavailablePackage::Forbidden);
+                    return Ok(None);
+                }
+
                 if let Some(url) = package.name().and_then(|name| fork_urls.get(name)) {
                     self.choose_version_url(name, range, url, python_requirement)
                 } else {
@@ -900,9 +1153,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
         let dist = PubGrubDistribution::from_url(name, url);
         let response = self
-            .index
-            .distributions()
-            .wait_blocking(&dist.version_id())
+            .wait_blocking(self.index.distributions(), &dist.version_id())?
             .ok_or_else(|| ResolveError::UnregisteredTask(dist.version_id().to_string()))?;
 
         // If we failed to fetch the metadata for a URL, we can't proceed.
@@ -1034,14 +1285,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
     ) -> Result<Option<ResolverVersion>, ResolveError> {
         // Wait for the metadata to be available.
         let versions_response = if let Some(index) = index {
-            self.index
-                .explicit()
-                .wait_blocking(&(name.clone(), index.clone()))
+            self.wait_blocking(self.index.explicit(), &(name.clone(), index.clone()))?
                 .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?
         } else {
-            self.index
-                .implicit()
-                .wait_blocking(name)
+            self.wait_blocking(self.index.implicit(), name)?
                 .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?
         };
         visited.insert(name.clone());
@@ -1287,9 +1534,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                 // Wait for the metadata to be available.
                 let response = self
-                    .index
-                    .distributions()
-                    .wait_blocking(&version_id)
+                    .wait_blocking(self.index.distributions(), &version_id)?
                     .ok_or_else(|| ResolveError::UnregisteredTask(version_id.to_string()))?;
 
                 let metadata = match &*response {
@@ -1853,6 +2098,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         match request {
             // Fetch package metadata from the registry.
             Request::Package(package_name, index) => {
+                self.packages_fetched.fetch_add(1, AtomicOrdering::Relaxed);
+                if let Some(reporter) = self.reporter.as_ref() {
+                    reporter.on_fetch_versions(&package_name);
+                }
+
                 let package_versions = provider
                     .get_package_versions(&package_name, index.as_ref())
                     .boxed_local()
@@ -1868,6 +2118,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
             // Fetch distribution metadata from the distribution database.
             Request::Dist(dist) => {
+                self.metadata_fetched.fetch_add(1, AtomicOrdering::Relaxed);
+                if let Some(reporter) = self.reporter.as_ref() {
+                    reporter.on_fetch_metadata(&dist);
+                }
+
                 let metadata = provider
                     .get_or_build_wheel_metadata(&dist)
                     .boxed_local()
@@ -2093,6 +2348,72 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
         }
 
+        // Track the inputs for each package in the derivation tree, to trace the failure back to
+        // its source.
+        let mut inputs: FxHashMap<PackageName, Vec<InputRequirement>> = FxHashMap::default();
+        for package in err.packages() {
+            let Some(name) = package.name() else { continue };
+            if inputs.contains_key(name) {
+                continue;
+            }
+            let entries = self
+                .requirements
+                .iter()
+                .filter(|requirement| requirement.name == *name)
+                .cloned()
+                .map(InputRequirement::Requirement)
+                .chain(
+                    self.constraints
+                        .requirements()
+                        .filter(|requirement| requirement.name == *name)
+                        .cloned()
+                        .map(InputRequirement::Constraint),
+                )
+                .chain(
+                    self.overrides
+                        .requirements()
+                        .filter(|requirement| requirement.name == *name)
+                        .cloned()
+                        .map(InputRequirement::Override),
+                )
+                .collect::<Vec<_>>();
+            if !entries.is_empty() {
+                inputs.insert(name.clone(), entries);
+            }
+        }
+
+        // Include the requirements declared by workspace members, which are the inputs that the
+        // user controls (rather than the requirement on the member itself).
+        let names = err
+            .packages()
+            .filter_map(|package| package.name())
+            .collect::<FxHashSet<_>>();
+        for member in &self.workspace_members {
+            let Some(url) = fork_urls.get(member) else {
+                continue;
+            };
+            let version_id = PubGrubDistribution::from_url(member, url).version_id();
+            let Some(response) = self.index.distributions().get(&version_id) else {
+                continue;
+            };
+            let MetadataResponse::Found(archive) = &*response else {
+                continue;
+            };
+            for requirement in archive
+                .metadata
+                .requires_dist
+                .iter()
+                .chain(archive.metadata.dependency_groups.values().flatten())
+            {
+                if names.contains(&requirement.name) {
+                    inputs
+                        .entry(requirement.name.clone())
+                        .or_default()
+                        .push(InputRequirement::Requirement(requirement.clone()));
+                }
+            }
+        }
+
         ResolveError::NoSolution(NoSolutionError::new(
             err,
             available_versions,
@@ -2106,7 +2427,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             fork_urls,
             env,
             self.workspace_members.clone(),
-            self.options,
+            self.options.clone(),
+            inputs,
         ))
     }
 
@@ -2125,6 +2447,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         }
     }
 
+    fn on_try_version(&self, package: &PubGrubPackage, version: &Version) {
+        if let Some(reporter) = self.reporter.as_ref() {
+            if let PubGrubPackageInner::Package { name, .. } = &**package {
+                reporter.on_try_version(name, version);
+            }
+        }
+    }
+
     fn on_complete(&self) {
         if let Some(reporter) = self.reporter.as_ref() {
             reporter.on_complete();
@@ -2175,6 +2505,11 @@ pub(crate) struct ForkState {
     /// This keeps track of the set of versions for each package that we've
     /// already visited during resolution. This avoids doing redundant work.
     added_dependencies: FxHashMap<PubGrubPackage, FxHashSet<Version>>,
+    /// The packages for which a version was decided in this fork, used to count backtracks.
+    ///
+    /// PubGrub only asks us to pick a version for undecided packages, so picking a package in
+    /// this set again means that its decision was retracted.
+    decided: FxHashSet<PubGrubPackage>,
     /// The marker expression that created this state.
     ///
     /// The root state always corresponds to a marker expression that is always
@@ -2220,6 +2555,7 @@ impl ForkState {
             fork_indexes: ForkIndexes::default(),
             priorities: PubGrubPriorities::default(),
             added_dependencies: FxHashMap::default(),
+            decided: FxHashSet::default(),
             env,
             python_requirement,
         }
@@ -2304,6 +2640,18 @@ impl ForkState {
                 (package, version)
             }),
         );
+
+        // PubGrub only makes the decision if the dependencies don't conflict with the partial
+        // solution.
+        if let Some(Term::Positive(range)) = self
+            .pubgrub
+            .partial_solution
+            .term_intersection_for_package(&self.next)
+        {
+            if range.as_singleton() == Some(for_version) {
+                self.decided.insert(self.next.clone());
+            }
+        }
         Ok(())
     }
 
@@ -2332,6 +2680,7 @@ impl ForkState {
             self.pubgrub
                 .partial_solution
                 .add_decision(self.next.clone(), version);
+            self.decided.insert(self.next.clone());
             return;
         };
         self.pubgrub
@@ -3151,3 +3500,250 @@ fn find_conflicting_extra(conflicting: &Conflicts, reqs: &[Requirement]) -> Opti
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::sync::Mutex;
+
+    use url::Url;
+
+    use uv_client::FlatIndexEntries;
+    use uv_configuration::BuildOptions;
+    use uv_distribution::Metadata;
+    use uv_distribution_filename::DistFilename;
+    use uv_distribution_types::{File, FileLocation, Name};
+    use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder};
+    use uv_types::EmptyInstalledPackages;
+
+    use crate::flat_index::FlatIndex;
+    use crate::version_map::VersionMap;
+    use crate::{OptionsBuilder, RequiresPython};
+
+    use super::*;
+
+    /// A [`ResolverProvider`] that serves wheels from memory and records every package it's asked
+    /// about.
+    struct MockProvider {
+        flat_index: FlatIndex,
+        requires_dist: FxHashMap<PackageName, Vec<Requirement>>,
+        requested: Mutex<Vec<PackageName>>,
+    }
+
+    impl MockProvider {
+        fn new(packages: &[(&str, &[&str])]) -> Self {
+            let url = Url::parse("https://example.com/wheels/").unwrap();
+            let entries = packages
+                .iter()
+                .map(|(name, _)| {
+                    let filename = format!("{name}-1.0.0-py3-none-any.whl");
+                    let file = File {
+                        dist_info_metadata: false,
+                        filename: filename.clone(),
+                        hashes: vec![],
+                        requires_python: None,
+                        size: None,
+                        upload_time_utc_ms: None,
+                        url: FileLocation::AbsoluteUrl(url.join(&filename).unwrap().into()),
+                        yanked: None,
+                    };
+                    (
+                        DistFilename::try_from_normalized_filename(&filename).unwrap(),
+                        file,
+                        IndexUrl::from_str(url.as_str()).unwrap(),
+                    )
+                })
+                .collect();
+            let flat_index = FlatIndex::from_entries(
+                FlatIndexEntries {
+                    entries,
+                    offline: false,
+                },
+                None,
+                &HashStrategy::default(),
+                &BuildOptions::default(),
+            );
+            let requires_dist = packages
+                .iter()
+                .map(|(name, requirements)| {
+                    (
+                        PackageName::from_str(name).unwrap(),
+                        requirements.iter().map(|req| requirement(req)).collect(),
+                    )
+                })
+                .collect();
+            Self {
+                flat_index,
+                requires_dist,
+                requested: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn requested(&self) -> Vec<PackageName> {
+            self.requested.lock().unwrap().clone()
+        }
+    }
+
+    impl ResolverProvider for &MockProvider {
+        async fn get_package_versions<'io>(
+            &'io self,
+            package_name: &'io PackageName,
+            _index: Option<&'io IndexUrl>,
+        ) -> PackageVersionsResult {
+            self.requested.lock().unwrap().push(package_name.clone());
+            match self.flat_index.get(package_name) {
+                Some(distributions) => Ok(VersionsResponse::Found(vec![VersionMap::from(
+                    distributions.clone(),
+                )])),
+                None => Ok(VersionsResponse::NotFound),
+            }
+        }
+
+        async fn get_or_build_wheel_metadata<'io>(
+            &'io self,
+            dist: &'io Dist,
+        ) -> WheelMetadataResult {
+            self.requested.lock().unwrap().push(dist.name().clone());
+            Ok(MetadataResponse::Found(ArchiveMetadata {
+                metadata: Metadata {
+                    name: dist.name().clone(),
+                    version: Version::new([1, 0, 0]),
+                    requires_dist: self.requires_dist[dist.name()].clone(),
+                    requires_python: None,
+                    provides_extras: vec![],
+                    dependency_groups: BTreeMap::new(),
+                },
+                hashes: vec![],
+            }))
+        }
+
+        fn with_reporter(self, _reporter: impl uv_distribution::Reporter + 'static) -> Self {
+            self
+        }
+    }
+
+    fn requirement(requirement: &str) -> Requirement {
+        Requirement::from(
+            uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement).unwrap(),
+        )
+    }
+
+    fn python_requirement() -> PythonRequirement {
+        let marker_env = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name: "cpython",
+            implementation_version: "3.12.0",
+            os_name: "posix",
+            platform_machine: "x86_64",
+            platform_python_implementation: "CPython",
+            platform_release: "",
+            platform_system: "Linux",
+            platform_version: "",
+            python_full_version: "3.12.0",
+            python_version: "3.12",
+            sys_platform: "linux",
+        })
+        .unwrap();
+        PythonRequirement::from_marker_environment(
+            &marker_env,
+            RequiresPython::greater_than_equal_version(&Version::new([3, 12])),
+        )
+    }
+
+    fn resolver<'a>(
+        manifest: Manifest,
+        provider: &'a MockProvider,
+    ) -> Resolver<&'a MockProvider, EmptyInstalledPackages> {
+        Resolver::new_custom_io(
+            manifest,
+            OptionsBuilder::new().build(),
+            &HashStrategy::default(),
+            ResolverEnvironment::universal(vec![]),
+            &python_requirement(),
+            Conflicts::empty(),
+            &InMemoryIndex::default(),
+            &GitResolver::default(),
+            &IndexCapabilities::default(),
+            &IndexLocations::default(),
+            provider,
+            EmptyInstalledPackages,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn forbidden_packages_are_not_fetched() {
+        let provider = MockProvider::new(&[("a", &["forbidden"]), ("forbidden", &[])]);
+        let manifest = Manifest::simple(vec![requirement("a")])
+            .with_forbidden_packages([PackageName::from_str("forbidden").unwrap()]);
+
+        let result = resolver(manifest, &provider).resolve().await;
+
+        assert!(matches!(result, Err(ResolveError::NoSolution(_))));
+        assert!(provider
+            .requested()
+            .contains(&PackageName::from_str("a").unwrap()));
+        assert!(!provider
+            .requested()
+            .contains(&PackageName::from_str("forbidden").unwrap()));
+    }
+
+    #[tokio::test]
+    async fn cancellation() {
+        let provider = MockProvider::new(&[("a", &[])]);
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+
+        let result = resolver(Manifest::simple(vec![requirement("a")]), &provider)
+            .with_cancellation(cancellation)
+            .resolve()
+            .await;
+        assert!(
+            matches!(result, Err(ResolveError::Cancelled { .. })),
+            "{result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn stats() {
+        let provider = MockProvider::new(&[("a", &["b"]), ("b", &["c"]), ("c", &[])]);
+
+        let output = resolver(Manifest::simple(vec![requirement("a")]), &provider)
+            .resolve()
+            .await
+            .unwrap();
+        let stats = output.stats();
+        assert_eq!(stats.packages_fetched, 3);
+        assert_eq!(stats.backtracks, 0);
+        assert_eq!(stats.builds, 0);
+        assert_eq!(stats.metadata_cache_hits, 0);
+        assert_eq!(stats.forks, 1);
+        assert!(stats.wait_time <= stats.solve_time);
+    }
+
+    #[tokio::test]
+    async fn max_decisions() {
+        let provider = MockProvider::new(&[("a", &["b"]), ("b", &["c"]), ("c", &[])]);
+
+        // The root, `a`, `b` and `c` each take one decision.
+        let result = resolver(Manifest::simple(vec![requirement("a")]), &provider)
+            .with_max_decisions(4)
+            .resolve()
+            .await;
+        assert!(result.is_ok(), "{result:?}");
+
+        let result = resolver(Manifest::simple(vec![requirement("a")]), &provider)
+            .with_max_decisions(2)
+            .resolve()
+            .await;
+        let Err(ResolveError::BacktrackingLimit { limit, packages }) = result else {
+            panic!("expected a backtracking limit error: {result:?}");
+        };
+        assert_eq!(limit, 2);
+        assert!(packages.contains(&ExploredPackage {
+            name: "a".to_string(),
+            decisions: 1,
+            range: Some((Version::new([1, 0, 0]), Version::new([1, 0, 0]))),
+        }));
+        assert!(!packages.iter().any(|package| package.name == "c"));
+    }
+}
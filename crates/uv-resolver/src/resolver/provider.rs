@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::future::Future;
 use std::sync::Arc;
 
@@ -9,7 +10,7 @@ use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::Tags;
 use uv_types::{BuildContext, HashStrategy};
 
-use crate::flat_index::FlatIndex;
+use crate::flat_index::{FlatDistributions, FlatIndex};
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
 use crate::{ExcludeNewer, RequiresPython};
@@ -86,6 +87,8 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext> {
     allowed_yanks: AllowedYanks,
     hasher: HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
+    /// Per-package cutoffs that take precedence over `exclude_newer`.
+    exclude_newer_packages: BTreeMap<PackageName, ExcludeNewer>,
     build_options: &'a BuildOptions,
     capabilities: &'a IndexCapabilities,
 }
@@ -111,10 +114,33 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             allowed_yanks,
             hasher: hasher.clone(),
             exclude_newer,
+            exclude_newer_packages: BTreeMap::new(),
             build_options,
             capabilities,
         }
     }
+
+    /// Set per-package [`ExcludeNewer`] cutoffs, which take precedence over the global cutoff.
+    #[must_use]
+    pub fn with_exclude_newer_packages(
+        self,
+        exclude_newer_packages: BTreeMap<PackageName, ExcludeNewer>,
+    ) -> Self {
+        Self {
+            exclude_newer_packages,
+            ..self
+        }
+    }
+
+    /// Return the `--find-links` distributions for the given package, applying the package's
+    /// [`ExcludeNewer`] cutoff, if any.
+    fn flat_distributions(&self, package_name: &PackageName) -> Option<FlatDistributions> {
+        let mut distributions = self.flat_index.get(package_name).cloned()?;
+        if let Some(exclude_newer) = self.exclude_newer_packages.get(package_name) {
+            distributions.exclude_newer(exclude_newer);
+        }
+        Some(distributions)
+    }
 }
 
 impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a, Context> {
@@ -143,8 +169,10 @@ impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a,
                             &self.requires_python,
                             &self.allowed_yanks,
                             &self.hasher,
-                            self.exclude_newer.as_ref(),
-                            self.flat_index.get(package_name).cloned(),
+                            self.exclude_newer_packages
+                                .get(package_name)
+                                .or(self.exclude_newer.as_ref()),
+                            self.flat_distributions(package_name),
                             self.build_options,
                         )
                     })
@@ -152,14 +180,14 @@ impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a,
             )),
             Err(err) => match err.into_kind() {
                 uv_client::ErrorKind::PackageNotFound(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
+                    if let Some(flat_index) = self.flat_distributions(package_name) {
                         Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
                     } else {
                         Ok(VersionsResponse::NotFound)
                     }
                 }
                 uv_client::ErrorKind::NoIndex(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
+                    if let Some(flat_index) = self.flat_distributions(package_name) {
                         Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
                     } else if self.flat_index.offline() {
                         Ok(VersionsResponse::Offline)
@@ -168,7 +196,7 @@ impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a,
                     }
                 }
                 uv_client::ErrorKind::Offline(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
+                    if let Some(flat_index) = self.flat_distributions(package_name) {
                         Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
                     } else {
                         Ok(VersionsResponse::Offline)
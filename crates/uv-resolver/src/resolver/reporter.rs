@@ -1,9 +1,11 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use url::Url;
 
-use uv_distribution_types::{BuildableSource, VersionOrUrlRef};
+use uv_distribution_types::{BuildableSource, Dist, VersionOrUrlRef};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 
 pub type BuildId = usize;
 
@@ -11,10 +13,21 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a dependency is resolved.
     fn on_progress(&self, name: &PackageName, version: &VersionOrUrlRef);
 
+    /// Callback to invoke when the resolver picks a candidate version of a package to try, before
+    /// it's known whether the version is usable.
+    fn on_try_version(&self, _name: &PackageName, _version: &Version) {}
+
     /// Callback to invoke when the resolution is complete.
     fn on_complete(&self);
 
-    /// Callback to invoke when a source distribution build is kicked off.
+    /// Callback to invoke when the available versions of a package are requested.
+    fn on_fetch_versions(&self, _name: &PackageName) {}
+
+    /// Callback to invoke when the metadata for a distribution is requested.
+    fn on_fetch_metadata(&self, _dist: &Dist) {}
+
+    /// Callback to invoke when a source distribution build is kicked off, e.g., to build a source
+    /// distribution for its metadata.
     fn on_build_start(&self, source: &BuildableSource) -> usize;
 
     /// Callback to invoke when a source distribution build is complete.
@@ -38,36 +51,60 @@ pub trait Reporter: Send + Sync {
 }
 
 /// A facade for converting from [`Reporter`] to [`uv_distribution::Reporter`].
+///
+/// The facade is installed even without a [`Reporter`], to count the builds and cached metadata
+/// reads reported by the provider for the [`ResolverStats`](crate::ResolverStats).
 pub(crate) struct Facade {
-    pub(crate) reporter: Arc<dyn Reporter>,
+    pub(crate) reporter: Option<Arc<dyn Reporter>>,
+    pub(crate) builds: Arc<AtomicUsize>,
+    pub(crate) metadata_cache_hits: Arc<AtomicUsize>,
 }
 
 impl uv_distribution::Reporter for Facade {
     fn on_build_start(&self, source: &BuildableSource) -> usize {
-        self.reporter.on_build_start(source)
+        self.builds.fetch_add(1, Ordering::Relaxed);
+        self.reporter
+            .as_ref()
+            .map_or(0, |reporter| reporter.on_build_start(source))
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
-        self.reporter.on_build_complete(source, id);
+        if let Some(reporter) = self.reporter.as_ref() {
+            reporter.on_build_complete(source, id);
+        }
+    }
+
+    fn on_metadata_cached(&self, _source: &BuildableSource) {
+        self.metadata_cache_hits.fetch_add(1, Ordering::Relaxed);
     }
 
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
-        self.reporter.on_checkout_start(url, rev)
+        self.reporter
+            .as_ref()
+            .map_or(0, |reporter| reporter.on_checkout_start(url, rev))
     }
 
     fn on_checkout_complete(&self, url: &Url, rev: &str, id: usize) {
-        self.reporter.on_checkout_complete(url, rev, id);
+        if let Some(reporter) = self.reporter.as_ref() {
+            reporter.on_checkout_complete(url, rev, id);
+        }
     }
 
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
-        self.reporter.on_download_start(name, size)
+        self.reporter
+            .as_ref()
+            .map_or(0, |reporter| reporter.on_download_start(name, size))
     }
 
     fn on_download_progress(&self, id: usize, bytes: u64) {
-        self.reporter.on_download_progress(id, bytes);
+        if let Some(reporter) = self.reporter.as_ref() {
+            reporter.on_download_progress(id, bytes);
+        }
     }
 
     fn on_download_complete(&self, name: &PackageName, id: usize) {
-        self.reporter.on_download_complete(name, id);
+        if let Some(reporter) = self.reporter.as_ref() {
+            reporter.on_download_complete(name, id);
+        }
     }
 }
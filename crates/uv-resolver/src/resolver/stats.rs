@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+/// Statistics about a single resolution, e.g., to track resolver performance across releases.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResolverStats {
+    /// The number of package versions that were tried, across all forks.
+    ///
+    /// Versions that are tried repeatedly during backtracking are counted each time.
+    pub versions_tried: usize,
+    /// The number of packages for which the available versions were requested.
+    pub packages_fetched: usize,
+    /// The number of distributions for which metadata was requested (from the cache, the
+    /// network, or by building a source distribution).
+    pub metadata_fetched: usize,
+    /// The number of source distributions whose metadata was read from the cache, rather than
+    /// built.
+    pub metadata_cache_hits: usize,
+    /// The number of source distributions (or source trees) that were built.
+    pub builds: usize,
+    /// The number of times the solver backtracked, counted as the number of decisions that were
+    /// retracted and later made again in the same fork.
+    pub backtracks: usize,
+    /// The number of forks that were solved.
+    pub forks: usize,
+    /// The wall time spent in the solver, including [`ResolverStats::wait_time`].
+    pub solve_time: Duration,
+    /// The wall time the solver spent blocked on version and metadata requests.
+    pub wait_time: Duration,
+}
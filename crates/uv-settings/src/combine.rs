@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
@@ -110,6 +111,20 @@ impl<T> Combine for Option<Vec<T>> {
     }
 }
 
+impl<K: Ord, V> Combine for Option<BTreeMap<K, V>> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`, preferring the entries in `self`.
+    fn combine(self, other: Option<BTreeMap<K, V>>) -> Option<BTreeMap<K, V>> {
+        match (self, other) {
+            (Some(a), Some(mut b)) => {
+                b.extend(a);
+                Some(b)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ConfigSettings> {
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
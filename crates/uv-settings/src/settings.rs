@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::{fmt::Debug, num::NonZeroUsize, path::PathBuf};
+use std::{collections::BTreeMap, fmt::Debug, num::NonZeroUsize, path::PathBuf};
 use url::Url;
 use uv_cache_info::CacheKey;
 use uv_configuration::{
@@ -307,9 +307,11 @@ pub struct ResolverOptions {
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
+    pub prerelease_package: Option<Vec<PackageName>>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer_package: Option<BTreeMap<PackageName, ExcludeNewer>>,
     pub link_mode: Option<LinkMode>,
     pub upgrade: Option<bool>,
     pub upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
@@ -483,6 +485,22 @@ pub struct ResolverInstallerOptions {
         possible_values = true
     )]
     pub prerelease: Option<PrereleaseMode>,
+    /// Allow pre-release versions for specific packages, regardless of the
+    /// [`prerelease`](#prerelease) strategy.
+    ///
+    /// The packages are allowed pre-releases wherever they appear in the dependency graph, while
+    /// the global strategy continues to apply to every other package.
+    ///
+    /// Only respected by the project and tool interfaces (e.g., `uv lock` and `uv tool install`),
+    /// not by the `uv pip` interface.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            prerelease-package = ["torch"]
+        "#
+    )]
+    pub prerelease_package: Option<Vec<PackageName>>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -553,6 +571,21 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub exclude_newer: Option<ExcludeNewer>,
+    /// Limit candidate packages for specific packages to those that were uploaded prior to the
+    /// given date, overriding [`exclude-newer`](#exclude-newer) for those packages.
+    ///
+    /// Accepts the same formats as [`exclude-newer`](#exclude-newer).
+    ///
+    /// Only respected by the project and tool interfaces (e.g., `uv lock` and `uv tool install`),
+    /// not by the `uv pip` interface.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            exclude-newer-package = { tqdm = "2022-04-04T00:00:00Z" }
+        "#
+    )]
+    pub exclude_newer_package: Option<BTreeMap<PackageName, ExcludeNewer>>,
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -1420,9 +1453,11 @@ impl From<ResolverInstallerOptions> for ResolverOptions {
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
             upgrade: value.upgrade,
             upgrade_package: value.upgrade_package,
@@ -1448,7 +1483,7 @@ impl From<ResolverInstallerOptions> for InstallerOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             config_settings: value.config_settings,
-            exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             reinstall: value.reinstall,
@@ -1482,11 +1517,13 @@ pub struct ToolOptions {
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
+    pub prerelease_package: Option<Vec<PackageName>>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub no_build_isolation: Option<bool>,
     pub no_build_isolation_package: Option<Vec<PackageName>>,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub exclude_newer_package: Option<BTreeMap<PackageName, ExcludeNewer>>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
     pub no_sources: Option<bool>,
@@ -1508,11 +1545,13 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             no_build_isolation: value.no_build_isolation,
             no_build_isolation_package: value.no_build_isolation_package,
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -1536,11 +1575,13 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             no_build_isolation: value.no_build_isolation,
             no_build_isolation_package: value.no_build_isolation_package,
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -1586,11 +1627,13 @@ pub struct OptionsWire {
     allow_insecure_host: Option<Vec<TrustedHost>>,
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
+    prerelease_package: Option<Vec<PackageName>>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     no_build_isolation: Option<bool>,
     no_build_isolation_package: Option<Vec<PackageName>>,
     exclude_newer: Option<ExcludeNewer>,
+    exclude_newer_package: Option<BTreeMap<PackageName, ExcludeNewer>>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
@@ -1666,11 +1709,13 @@ impl From<OptionsWire> for Options {
             allow_insecure_host,
             resolution,
             prerelease,
+            prerelease_package,
             dependency_metadata,
             config_settings,
             no_build_isolation,
             no_build_isolation_package,
             exclude_newer,
+            exclude_newer_package,
             link_mode,
             compile_bytecode,
             no_sources,
@@ -1726,11 +1771,13 @@ impl From<OptionsWire> for Options {
                 keyring_provider,
                 resolution,
                 prerelease,
+                prerelease_package,
                 dependency_metadata,
                 config_settings,
                 no_build_isolation,
                 no_build_isolation_package,
                 exclude_newer,
+                exclude_newer_package,
                 link_mode,
                 compile_bytecode,
                 no_sources,
@@ -27,6 +27,8 @@ pub enum Shell {
     Csh,
     /// Korn SHell (ksh)
     Ksh,
+    /// Xonsh
+    Xonsh,
 }
 
 impl Shell {
@@ -43,6 +45,8 @@ impl Shell {
     pub fn from_env() -> Option<Shell> {
         if std::env::var_os(EnvVars::NU_VERSION).is_some() {
             Some(Shell::Nushell)
+        } else if std::env::var_os(EnvVars::XONSH_VERSION).is_some() {
+            Some(Shell::Xonsh)
         } else if std::env::var_os(EnvVars::FISH_VERSION).is_some() {
             Some(Shell::Fish)
         } else if std::env::var_os(EnvVars::BASH_VERSION).is_some() {
@@ -164,6 +168,10 @@ impl Shell {
                 // On Csh, we need to update both `.cshrc` and `.login`, like Bash.
                 vec![home_dir.join(".cshrc"), home_dir.join(".login")]
             }
+            Shell::Xonsh => {
+                // On Xonsh, `.xonshrc` is sourced for both login and non-login interactive shells.
+                vec![home_dir.join(".xonshrc")]
+            }
             // TODO(charlie): Add support for Nushell.
             Shell::Nushell => vec![],
             // See: [`crate::windows::prepend_path`].
@@ -213,9 +221,15 @@ impl Shell {
                 "setenv PATH \"{}:$PATH\"",
                 backslash_escape(&path.simplified_display().to_string()),
             )),
+            Shell::Xonsh => Some(format!(
+                "$PATH.insert(0, \"{}\")",
+                backslash_escape(&path.simplified_display().to_string()),
+            )),
             Shell::Powershell => Some(format!(
-                "$env:PATH = \"{};$env:PATH\"",
+                "$env:PATH = \"{}{}$env:PATH\"",
                 backtick_escape(&path.simplified_display().to_string()),
+                // PowerShell Core on Unix uses the platform's `PATH` separator.
+                if cfg!(windows) { ";" } else { ":" },
             )),
             Shell::Cmd => Some(format!(
                 "set PATH=\"{};%PATH%\"",
@@ -236,6 +250,7 @@ impl std::fmt::Display for Shell {
             Shell::Nushell => write!(f, "Nushell"),
             Shell::Csh => write!(f, "Csh"),
             Shell::Ksh => write!(f, "Ksh"),
+            Shell::Xonsh => write!(f, "Xonsh"),
         }
     }
 }
@@ -249,7 +264,8 @@ fn parse_shell_from_path(path: &Path) -> Option<Shell> {
         "fish" => Some(Shell::Fish),
         "csh" => Some(Shell::Csh),
         "ksh" => Some(Shell::Ksh),
-        "powershell" | "powershell_ise" => Some(Shell::Powershell),
+        "xonsh" => Some(Shell::Xonsh),
+        "powershell" | "powershell_ise" | "pwsh" => Some(Shell::Powershell),
         _ => None,
     }
 }
@@ -366,6 +366,9 @@ impl EnvVars {
     /// Used to detect Ksh shell usage.
     pub const KSH_VERSION: &'static str = "KSH_VERSION";
 
+    /// Used to detect Xonsh shell usage.
+    pub const XONSH_VERSION: &'static str = "XONSH_VERSION";
+
     /// Used with `--python-platform macos` and related variants to set the
     /// deployment target (i.e., the minimum supported macOS version).
     ///
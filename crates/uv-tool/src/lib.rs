@@ -265,11 +265,7 @@ impl InstalledTools {
         let venv = uv_virtualenv::create_venv(
             &environment_path,
             interpreter,
-            uv_virtualenv::Prompt::None,
-            false,
-            false,
-            false,
-            false,
+            uv_virtualenv::VenvOptions::default(),
         )?;
 
         Ok(venv)
@@ -1,8 +1,9 @@
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use thiserror::Error;
 
+use uv_fs::Simplified;
 use uv_platform_tags::PlatformError;
 use uv_python::{Interpreter, PythonEnvironment};
 
@@ -20,6 +21,14 @@ pub enum Error {
     Platform(#[from] PlatformError),
     #[error("Could not find a suitable Python executable for the virtual environment based on the interpreter: {0}")]
     NotFound(String),
+    #[error("Invalid `pyvenv.cfg` entry `{0}`: keys must be non-empty, must not contain `=` or line breaks, and must not be set by uv itself")]
+    InvalidPyVenvCfgEntry(String),
+    #[error(transparent)]
+    VirtualEnv(#[from] uv_python::VirtualEnvError),
+    #[error("The Python executable at `{}` links against a shared `libpython`, so it can't be hard linked or copied into a virtual environment", .0.user_display())]
+    SharedLibpython(PathBuf),
+    #[error("Cannot re-link a virtual environment for {previous} to {requested}, as installed packages are specific to the Python implementation, version, and ABI; recreate the environment instead")]
+    RelinkVersionMismatch { previous: String, requested: String },
 }
 
 /// The value to use for the shell prompt when inside a virtual environment.
@@ -45,29 +54,85 @@ impl Prompt {
     }
 }
 
+/// How the base Python executable is placed into the virtual environment.
+///
+/// Only applies on Unix; on Windows, the launcher shims (or the executable itself) are always
+/// copied. The version-specific aliases (e.g., `python3.12`) are always symlinks to the
+/// environment's own `python`. Hard linking and copying aren't supported for interpreters that
+/// link against a shared `libpython`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InterpreterLinkMode {
+    /// Symbolically link to the base executable.
+    #[default]
+    Symlink,
+    /// Hard link to the base executable, falling back to a copy if hard linking fails (e.g., on a
+    /// different filesystem).
+    Hardlink,
+    /// Copy the base executable, for filesystems and tools that can't handle links.
+    Copy,
+}
+
+/// Options for creating a virtualenv.
+#[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct VenvOptions {
+    /// The value to use for the shell prompt when inside the virtual environment.
+    pub prompt: Prompt,
+    /// Give the virtual environment access to the system `site-packages` directory.
+    pub system_site_packages: bool,
+    /// Reuse an existing directory at the location, rather than replacing it.
+    pub allow_existing: bool,
+    /// Make the virtual environment relocatable.
+    pub relocatable: bool,
+    /// Record that the virtual environment is populated with seed packages.
+    pub seed: bool,
+    /// How to place the base Python executable into the virtual environment.
+    pub link_mode: InterpreterLinkMode,
+    /// Write the shell activation scripts.
+    pub activators: bool,
+    /// Additional entries to write to `pyvenv.cfg`.
+    pub extra_cfg: Vec<(String, String)>,
+}
+
+impl Default for VenvOptions {
+    fn default() -> Self {
+        Self {
+            prompt: Prompt::None,
+            system_site_packages: false,
+            allow_existing: false,
+            relocatable: false,
+            seed: false,
+            link_mode: InterpreterLinkMode::default(),
+            activators: true,
+            extra_cfg: Vec::new(),
+        }
+    }
+}
+
 /// Create a virtualenv.
-#[allow(clippy::fn_params_excessive_bools)]
 pub fn create_venv(
     location: &Path,
     interpreter: Interpreter,
-    prompt: Prompt,
-    system_site_packages: bool,
-    allow_existing: bool,
-    relocatable: bool,
-    seed: bool,
+    options: VenvOptions,
 ) -> Result<PythonEnvironment, Error> {
     // Create the virtualenv at the given location.
-    let virtualenv = virtualenv::create(
-        location,
-        &interpreter,
-        prompt,
-        system_site_packages,
-        allow_existing,
-        relocatable,
-        seed,
-    )?;
+    let virtualenv = virtualenv::create(location, &interpreter, options)?;
 
     // Create the corresponding `PythonEnvironment`.
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// Re-link an existing virtualenv against a new base interpreter, keeping its installed packages.
+///
+/// This repairs environments whose base interpreter was upgraded or removed (see
+/// [`uv_python::InvalidEnvironmentKind::MissingBaseInterpreter`]).
+pub fn relink_venv(
+    location: &Path,
+    interpreter: Interpreter,
+    link_mode: InterpreterLinkMode,
+) -> Result<PythonEnvironment, Error> {
+    let virtualenv = virtualenv::relink(location, &interpreter, link_mode)?;
+    let interpreter = interpreter.with_virtualenv(virtualenv);
+    Ok(PythonEnvironment::from_interpreter(interpreter))
+}
@@ -3,20 +3,20 @@
 use std::env::consts::EXE_SUFFIX;
 use std::io;
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use fs_err as fs;
 use fs_err::File;
 use itertools::Itertools;
 use tracing::debug;
 
-use uv_fs::{cachedir, Simplified, CWD};
+use uv_fs::{cachedir, LockedFile, Simplified, CWD};
 use uv_pypi_types::Scheme;
-use uv_python::{Interpreter, VirtualEnvironment};
+use uv_python::{Interpreter, PyVenvConfiguration, VirtualEnvironment};
 use uv_shell::escape_posix_for_single_quotes;
 use uv_version::version;
 
-use crate::{Error, Prompt};
+use crate::{Error, InterpreterLinkMode, Prompt, VenvOptions};
 
 /// Activation scripts for the environment, with dependent paths templated out.
 const ACTIVATE_TEMPLATES: &[(&str, &str)] = &[
@@ -25,6 +25,7 @@ const ACTIVATE_TEMPLATES: &[(&str, &str)] = &[
     ("activate.fish", include_str!("activator/activate.fish")),
     ("activate.nu", include_str!("activator/activate.nu")),
     ("activate.ps1", include_str!("activator/activate.ps1")),
+    ("activate.xsh", include_str!("activator/activate.xsh")),
     ("activate.bat", include_str!("activator/activate.bat")),
     ("deactivate.bat", include_str!("activator/deactivate.bat")),
     ("pydoc.bat", include_str!("activator/pydoc.bat")),
@@ -35,6 +36,25 @@ const ACTIVATE_TEMPLATES: &[(&str, &str)] = &[
 ];
 const VIRTUALENV_PATCH: &str = include_str!("_virtualenv.py");
 
+/// The file name prefix of the directories in which environments are staged before being moved
+/// into place.
+const STAGING_PREFIX: &str = ".tmp-venv";
+
+/// The `pyvenv.cfg` keys written by uv (or that would change how uv reads the environment),
+/// which can't be overridden by callers. Keys are matched case-insensitively.
+const RESERVED_CFG_KEYS: &[&str] = &[
+    "home",
+    "implementation",
+    "uv",
+    "version_info",
+    "include-system-site-packages",
+    "relocatable",
+    "seed",
+    "prompt",
+    "venvlauncher_command",
+    "virtualenv",
+];
+
 /// Very basic `.cfg` file format writer.
 fn write_cfg(f: &mut impl Write, data: &[(String, String)]) -> io::Result<()> {
     for (key, value) in data {
@@ -44,38 +64,45 @@ fn write_cfg(f: &mut impl Write, data: &[(String, String)]) -> io::Result<()> {
 }
 
 /// Create a [`VirtualEnvironment`] at the given location.
-#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn create(
     location: &Path,
     interpreter: &Interpreter,
-    prompt: Prompt,
-    system_site_packages: bool,
-    allow_existing: bool,
-    relocatable: bool,
-    seed: bool,
+    options: VenvOptions,
 ) -> Result<VirtualEnvironment, Error> {
-    // Determine the base Python executable; that is, the Python executable that should be
-    // considered the "base" for the virtual environment. This is typically the Python executable
-    // from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
-    // the base Python executable is the Python executable of the interpreter's base interpreter.
-    let base_python = if cfg!(unix) && interpreter.is_standalone() {
-        // In `python-build-standalone`, a symlinked interpreter will return its own executable path
-        // as `sys._base_executable`. Using the symlinked path as the base Python executable is
-        // incorrect,  since it will cause `home` to point to something that is _not_ a Python
-        // installation.
-        //
-        // Instead, we want to fully resolve the symlink to the actual Python executable.
-        uv_fs::canonicalize_executable(interpreter.sys_executable())?
-    } else {
-        std::path::absolute(
-            interpreter
-                .sys_base_executable()
-                .unwrap_or(interpreter.sys_executable()),
-        )?
-    };
+    let VenvOptions {
+        prompt,
+        system_site_packages,
+        allow_existing,
+        relocatable,
+        seed,
+        link_mode,
+        activators,
+        extra_cfg,
+    } = options;
+
+    // Validate any additional `pyvenv.cfg` entries before touching the file system.
+    for (key, value) in &extra_cfg {
+        let key_trimmed = key.trim();
+        if key_trimmed.is_empty()
+            || key.contains(['=', '\n', '\r'])
+            || value.contains(['\n', '\r'])
+            || RESERVED_CFG_KEYS
+                .iter()
+                .any(|reserved| reserved.eq_ignore_ascii_case(key_trimmed))
+        {
+            return Err(Error::InvalidPyVenvCfgEntry(key.clone()));
+        }
+    }
+
+    let base_python = base_python(interpreter)?;
+
+    let location = std::path::absolute(location)?;
 
-    // Validate the existing location.
-    match location.metadata() {
+    // Validate the existing location. Unless we're reusing an existing directory, the environment
+    // is built in a sibling staging directory and renamed into place once complete, so that an
+    // interrupted creation never leaves behind a partial environment.
+    let mut replace_existing = false;
+    let staging = match location.metadata() {
         Ok(metadata) => {
             if metadata.is_file() {
                 return Err(Error::Io(io::Error::new(
@@ -85,15 +112,17 @@ pub(crate) fn create(
             } else if metadata.is_dir() {
                 if allow_existing {
                     debug!("Allowing existing directory");
+                    None
                 } else if location.join("pyvenv.cfg").is_file() {
-                    debug!("Removing existing directory");
-                    fs::remove_dir_all(location)?;
-                    fs::create_dir_all(location)?;
+                    debug!("Replacing existing directory");
+                    replace_existing = true;
+                    Some(StagingDir::new(&location)?)
                 } else if location
                     .read_dir()
                     .is_ok_and(|mut dir| dir.next().is_none())
                 {
                     debug!("Ignoring empty directory");
+                    None
                 } else {
                     return Err(Error::Io(io::Error::new(
                         io::ErrorKind::AlreadyExists,
@@ -103,15 +132,19 @@ pub(crate) fn create(
                         ),
                     )));
                 }
+            } else {
+                None
             }
         }
-        Err(err) if err.kind() == io::ErrorKind::NotFound => {
-            fs::create_dir_all(location)?;
-        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Some(StagingDir::new(&location)?),
         Err(err) => return Err(Error::Io(err)),
-    }
+    };
 
-    let location = std::path::absolute(location)?;
+    // The directory to write the environment into, which is either the staging directory or the
+    // final location.
+    let root = staging
+        .as_ref()
+        .map_or(location.as_path(), StagingDir::path);
 
     let bin_name = if cfg!(unix) {
         "bin"
@@ -120,7 +153,7 @@ pub(crate) fn create(
     } else {
         unimplemented!("Only Windows and Unix are supported")
     };
-    let scripts = location.join(&interpreter.virtualenv().scripts);
+    let scripts = root.join(&interpreter.virtualenv().scripts);
     let prompt = match prompt {
         Prompt::CurrentDirectoryName => CWD
             .file_name()
@@ -130,59 +163,424 @@ pub(crate) fn create(
     };
 
     // Add the CACHEDIR.TAG.
-    cachedir::ensure_tag(&location)?;
+    cachedir::ensure_tag(root)?;
 
     // Create a `.gitignore` file to ignore all files in the venv.
-    fs::write(location.join(".gitignore"), "*")?;
+    fs::write(root.join(".gitignore"), "*")?;
 
     // Per PEP 405, the Python `home` is the parent directory of the interpreter.
-    let python_home = base_python.parent().ok_or_else(|| {
+    let python_home = python_home(&base_python)?;
+
+    link_interpreter(interpreter, &base_python, &scripts, python_home, link_mode)?;
+
+    // Add all the activate scripts for different shells
+    if activators {
+        for (name, template) in ACTIVATE_TEMPLATES {
+            let path_sep = if cfg!(windows) { ";" } else { ":" };
+
+            let relative_site_packages = [
+                interpreter.virtualenv().purelib.as_path(),
+                interpreter.virtualenv().platlib.as_path(),
+            ]
+            .iter()
+            .dedup()
+            .map(|path| {
+                pathdiff::diff_paths(path, &interpreter.virtualenv().scripts)
+                    .expect("Failed to calculate relative path to site-packages")
+            })
+            .map(|path| path.simplified().to_str().unwrap().replace('\\', "\\\\"))
+            .join(path_sep);
+
+            let virtual_env_dir = match (relocatable, name.to_owned()) {
+                (true, "activate") => {
+                    r#"'"$(dirname -- "$(dirname -- "$(realpath -- "$SCRIPT_PATH")")")"'"#
+                        .to_string()
+                }
+                (true, "activate.bat") => r"%~dp0..".to_string(),
+                (true, "activate.fish") => {
+                    r#"'"$(dirname -- "$(cd "$(dirname -- "$(status -f)")"; and pwd)")"'"#
+                        .to_string()
+                }
+                // `activate.xsh` is Python code, so the directory is a Python expression: `xonsh`
+                // sets `__file__` when sourcing a script.
+                (true, "activate.xsh") => {
+                    "_os_path.dirname(_os_path.dirname(_os_path.realpath(__file__)))".to_string()
+                }
+                // Note:
+                // * relocatable activate scripts appear not to be possible in csh and nu shell
+                // * `activate.ps1` is already relocatable by default.
+                // * `activate.xsh` is Python code, so the path must form a valid string literal.
+                (_, "activate.xsh") => format!(
+                    "\"{}\"",
+                    location
+                        .simplified()
+                        .to_str()
+                        .unwrap()
+                        .replace('\\', "\\\\")
+                        .replace('"', "\\\"")
+                ),
+                _ => escape_posix_for_single_quotes(location.simplified().to_str().unwrap()),
+            };
+
+            let activator = template
+                .replace("{{ VIRTUAL_ENV_DIR }}", &virtual_env_dir)
+                .replace("{{ BIN_NAME }}", bin_name)
+                .replace(
+                    "{{ VIRTUAL_PROMPT }}",
+                    prompt.as_deref().unwrap_or_default(),
+                )
+                .replace("{{ PATH_SEP }}", path_sep)
+                .replace("{{ RELATIVE_SITE_PACKAGES }}", &relative_site_packages);
+            fs::write(scripts.join(name), activator)?;
+        }
+    }
+
+    let mut pyvenv_cfg_data: Vec<(String, String)> = vec![
+        (
+            "home".to_string(),
+            python_home.simplified_display().to_string(),
+        ),
+        (
+            "implementation".to_string(),
+            interpreter
+                .markers()
+                .platform_python_implementation()
+                .to_string(),
+        ),
+        ("uv".to_string(), version().to_string()),
+        (
+            "version_info".to_string(),
+            interpreter.markers().python_full_version().string.clone(),
+        ),
+        (
+            "include-system-site-packages".to_string(),
+            if system_site_packages {
+                "true".to_string()
+            } else {
+                "false".to_string()
+            },
+        ),
+    ];
+
+    if relocatable {
+        pyvenv_cfg_data.push(("relocatable".to_string(), "true".to_string()));
+    }
+
+    if seed {
+        pyvenv_cfg_data.push(("seed".to_string(), "true".to_string()));
+    }
+
+    if let Some(prompt) = prompt {
+        pyvenv_cfg_data.push(("prompt".to_string(), prompt));
+    }
+
+    if cfg!(windows) && interpreter.markers().implementation_name() == "graalpy" {
+        pyvenv_cfg_data.push((
+            "venvlauncher_command".to_string(),
+            python_home
+                .join("graalpy.exe")
+                .simplified_display()
+                .to_string(),
+        ));
+    }
+
+    pyvenv_cfg_data.extend(extra_cfg);
+
+    let mut pyvenv_cfg = BufWriter::new(File::create(root.join("pyvenv.cfg"))?);
+    write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
+    drop(pyvenv_cfg);
+
+    // Construct the path to the `site-packages` directory.
+    let site_packages = root.join(&interpreter.virtualenv().purelib);
+    fs::create_dir_all(&site_packages)?;
+
+    // If necessary, create a symlink from `lib64` to `lib`.
+    // See: https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L135C11-L135C16
+    #[cfg(unix)]
+    if interpreter.pointer_size().is_64()
+        && interpreter.markers().os_name() == "posix"
+        && interpreter.markers().sys_platform() != "darwin"
+    {
+        match std::os::unix::fs::symlink("lib", root.join("lib64")) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(err) => {
+                return Err(err.into());
+            }
+        }
+    }
+
+    // Populate `site-packages` with a `_virtualenv.py` file.
+    fs::write(site_packages.join("_virtualenv.py"), VIRTUALENV_PATCH)?;
+    fs::write(site_packages.join("_virtualenv.pth"), "import _virtualenv")?;
+
+    // Move the completed environment into place.
+    if let Some(staging) = staging {
+        // Move any existing environment aside, rather than removing it up front, so that it's only
+        // deleted once its replacement is in place.
+        let previous = if replace_existing {
+            let mut previous = staging.path().as_os_str().to_owned();
+            previous.push("-previous");
+            let previous = PathBuf::from(previous);
+            debug!("Moving existing directory aside");
+            uv_fs::rename_with_retry_sync(&location, &previous)?;
+            Some(previous)
+        } else {
+            None
+        };
+
+        if let Err(err) = uv_fs::rename_with_retry_sync(staging.path(), &location) {
+            if let Some(previous) = previous {
+                debug!("Restoring existing directory");
+                uv_fs::rename_with_retry_sync(&previous, &location)?;
+            }
+            return Err(err.into());
+        }
+
+        if let Some(previous) = previous {
+            debug!("Removing existing directory");
+            if let Err(err) = fs::remove_dir_all(&previous) {
+                // Any leftovers are removed the next time an environment is created here.
+                debug!(
+                    "Failed to remove existing directory at `{}`: {err}",
+                    previous.user_display()
+                );
+            }
+        }
+
+        // Release the staging lock only once the replaced environment is gone.
+        drop(staging);
+    }
+
+    Ok(VirtualEnvironment {
+        scheme: Scheme {
+            purelib: location.join(&interpreter.virtualenv().purelib),
+            platlib: location.join(&interpreter.virtualenv().platlib),
+            scripts: location.join(&interpreter.virtualenv().scripts),
+            data: location.join(&interpreter.virtualenv().data),
+            include: location.join(&interpreter.virtualenv().include),
+        },
+        executable: location
+            .join(&interpreter.virtualenv().scripts)
+            .join(format!("python{EXE_SUFFIX}")),
+        root: location,
+    })
+}
+
+/// Re-link the virtual environment at the given location against a new base interpreter, e.g.,
+/// after the original base interpreter was upgraded or removed.
+///
+/// Replaces the interpreter links in the environment and rewrites the `home`, `implementation`,
+/// and `version_info` keys in `pyvenv.cfg`, leaving installed packages and all other `pyvenv.cfg`
+/// entries in place.
+pub(crate) fn relink(
+    location: &Path,
+    interpreter: &Interpreter,
+    link_mode: InterpreterLinkMode,
+) -> Result<VirtualEnvironment, Error> {
+    let location = std::path::absolute(location)?;
+    let cfg = PyVenvConfiguration::parse(location.join("pyvenv.cfg"))?;
+
+    // Installed packages are specific to the Python implementation, the minor version (e.g.,
+    // `lib/python3.12`), and whether the GIL is disabled (e.g., `lib/python3.13t`), so they're
+    // only usable if none of these change.
+    let requested = format!(
+        "{} {}.{}{}",
+        interpreter.markers().platform_python_implementation(),
+        interpreter.python_major(),
+        interpreter.python_minor(),
+        if interpreter.gil_disabled() { "t" } else { "" }
+    );
+    if let Some(previous) = previous_python(&location, &cfg, interpreter) {
+        if previous != requested {
+            return Err(Error::RelinkVersionMismatch {
+                previous,
+                requested,
+            });
+        }
+    }
+
+    let base_python = base_python(interpreter)?;
+    let python_home = python_home(&base_python)?;
+    let scripts = location.join(&interpreter.virtualenv().scripts);
+
+    debug!(
+        "Re-linking virtual environment at `{}` to `{}`",
+        location.user_display(),
+        base_python.user_display()
+    );
+    link_interpreter(interpreter, &base_python, &scripts, python_home, link_mode)?;
+
+    let pyvenv_cfg_data: Vec<(String, String)> = cfg
+        .entries()
+        .map(|(key, value)| {
+            let value = match key {
+                "home" => python_home.simplified_display().to_string(),
+                "implementation" => interpreter
+                    .markers()
+                    .platform_python_implementation()
+                    .to_string(),
+                "version_info" => interpreter.markers().python_full_version().string.clone(),
+                _ => value.to_string(),
+            };
+            (key.to_string(), value)
+        })
+        .collect();
+
+    let mut pyvenv_cfg = BufWriter::new(File::create(location.join("pyvenv.cfg"))?);
+    write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
+    drop(pyvenv_cfg);
+
+    Ok(VirtualEnvironment {
+        scheme: Scheme {
+            purelib: location.join(&interpreter.virtualenv().purelib),
+            platlib: location.join(&interpreter.virtualenv().platlib),
+            scripts: location.join(&interpreter.virtualenv().scripts),
+            data: location.join(&interpreter.virtualenv().data),
+            include: location.join(&interpreter.virtualenv().include),
+        },
+        executable: scripts.join(format!("python{EXE_SUFFIX}")),
+        root: location,
+    })
+}
+
+/// Describe the Python that the virtual environment at the given location was created for, in the
+/// same format as [`relink`] describes the requested interpreter (e.g., `CPython 3.13t`).
+///
+/// Returns `None` if `pyvenv.cfg` doesn't record the Python version. If it doesn't record the
+/// implementation, or if free-threading can't be detected from the layout (on Windows, all
+/// versions share `Lib/site-packages`), the requested interpreter's is assumed.
+fn previous_python(
+    location: &Path,
+    cfg: &PyVenvConfiguration,
+    interpreter: &Interpreter,
+) -> Option<String> {
+    let version_info = cfg.get("version_info")?;
+    let implementation = cfg
+        .get("implementation")
+        .unwrap_or(interpreter.markers().platform_python_implementation());
+
+    let mut parts = version_info.split('.');
+    let (Some(major), Some(minor)) = (parts.next(), parts.next()) else {
+        return Some(format!("{implementation} {version_info}"));
+    };
+    let gil_disabled = if cfg!(unix) {
+        location
+            .join("lib")
+            .join(format!("python{major}.{minor}t"))
+            .is_dir()
+    } else {
+        interpreter.gil_disabled()
+    };
+    Some(format!(
+        "{implementation} {major}.{minor}{}",
+        if gil_disabled { "t" } else { "" }
+    ))
+}
+
+/// Determine the base Python executable; that is, the Python executable that should be
+/// considered the "base" for the virtual environment. This is typically the Python executable
+/// from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
+/// the base Python executable is the Python executable of the interpreter's base interpreter.
+fn base_python(interpreter: &Interpreter) -> io::Result<PathBuf> {
+    if cfg!(unix) && interpreter.is_standalone() {
+        // In `python-build-standalone`, a symlinked interpreter will return its own executable path
+        // as `sys._base_executable`. Using the symlinked path as the base Python executable is
+        // incorrect,  since it will cause `home` to point to something that is _not_ a Python
+        // installation.
+        //
+        // Instead, we want to fully resolve the symlink to the actual Python executable.
+        uv_fs::canonicalize_executable(interpreter.sys_executable())
+    } else {
+        let base_python = std::path::absolute(
+            interpreter
+                .sys_base_executable()
+                .unwrap_or(interpreter.sys_executable()),
+        )?;
+
+        // In macOS framework builds (e.g., the python.org installers), the base executable is
+        // commonly reached through a symlink from outside the framework (e.g.,
+        // `/usr/local/bin/python3`), or through `Versions/Current`. Resolve it into the
+        // framework, such that `home` points at the framework's own `bin` directory.
+        if cfg!(target_os = "macos") {
+            Ok(resolve_framework_executable(&base_python))
+        } else {
+            Ok(base_python)
+        }
+    }
+}
+
+/// Per PEP 405, the Python `home` is the parent directory of the interpreter.
+fn python_home(base_python: &Path) -> io::Result<&Path> {
+    base_python.parent().ok_or_else(|| {
         io::Error::new(
             io::ErrorKind::NotFound,
             "The Python interpreter needs to have a parent directory",
         )
-    })?;
+    })
+}
 
+/// Place the base Python executable, and its aliases, in the environment's scripts directory.
+fn link_interpreter(
+    interpreter: &Interpreter,
+    base_python: &Path,
+    scripts: &Path,
+    python_home: &Path,
+    link_mode: InterpreterLinkMode,
+) -> Result<(), Error> {
     // Different names for the python interpreter
-    fs::create_dir_all(&scripts)?;
-    let executable = scripts.join(format!("python{EXE_SUFFIX}"));
+    fs::create_dir_all(scripts)?;
 
     #[cfg(unix)]
     {
-        uv_fs::replace_symlink(&base_python, &executable)?;
-        uv_fs::replace_symlink(
-            "python",
-            scripts.join(format!("python{}", interpreter.python_major())),
-        )?;
-        uv_fs::replace_symlink(
-            "python",
-            scripts.join(format!(
+        let mut aliases = vec![
+            format!("python{}", interpreter.python_major()),
+            format!(
                 "python{}.{}",
                 interpreter.python_major(),
                 interpreter.python_minor(),
-            )),
-        )?;
+            ),
+        ];
 
         if interpreter.markers().implementation_name() == "pypy" {
-            uv_fs::replace_symlink(
-                "python",
-                scripts.join(format!("pypy{}", interpreter.python_major())),
-            )?;
-            uv_fs::replace_symlink("python", scripts.join("pypy"))?;
+            aliases.push(format!("pypy{}", interpreter.python_major()));
+            aliases.push("pypy".to_string());
         }
 
         if interpreter.markers().implementation_name() == "graalpy" {
-            uv_fs::replace_symlink("python", scripts.join("graalpy"))?;
+            aliases.push("graalpy".to_string());
+        }
+
+        // Builds that link against a shared `libpython` typically find it relative to the
+        // executable (e.g., via an `$ORIGIN/../lib` rpath), which breaks once the executable is
+        // hard linked or copied into the environment.
+        if link_mode != InterpreterLinkMode::Symlink && interpreter.enable_shared() {
+            return Err(Error::SharedLibpython(base_python.to_path_buf()));
+        }
+
+        link_executable(
+            link_mode,
+            base_python,
+            &scripts.join(format!("python{EXE_SUFFIX}")),
+        )?;
+
+        // Even when the executable itself is copied, the aliases can point at the copy.
+        for alias in aliases {
+            uv_fs::replace_symlink("python", scripts.join(alias))?;
         }
     }
 
     // No symlinking on Windows, at least not on a regular non-dev non-admin Windows install.
     if cfg!(windows) {
+        if link_mode != InterpreterLinkMode::Symlink {
+            debug!("Ignoring interpreter link mode on Windows, where launchers are always copied");
+        }
+
         copy_launcher_windows(
             WindowsExecutable::Python,
             interpreter,
-            &base_python,
-            &scripts,
+            base_python,
+            scripts,
             python_home,
         )?;
 
@@ -190,23 +588,23 @@ pub(crate) fn create(
             copy_launcher_windows(
                 WindowsExecutable::GraalPy,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PythonMajor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
         } else {
             copy_launcher_windows(
                 WindowsExecutable::Pythonw,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
         }
@@ -215,50 +613,50 @@ pub(crate) fn create(
             copy_launcher_windows(
                 WindowsExecutable::PythonMajor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PythonMajorMinor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPy,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPyMajor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPyMajorMinor,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPyw,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
             copy_launcher_windows(
                 WindowsExecutable::PyPyMajorMinorw,
                 interpreter,
-                &base_python,
-                &scripts,
+                base_python,
+                scripts,
                 python_home,
             )?;
         }
@@ -269,137 +667,222 @@ pub(crate) fn create(
         compile_error!("Only Windows and Unix are supported")
     }
 
-    // Add all the activate scripts for different shells
-    for (name, template) in ACTIVATE_TEMPLATES {
-        let path_sep = if cfg!(windows) { ";" } else { ":" };
-
-        let relative_site_packages = [
-            interpreter.virtualenv().purelib.as_path(),
-            interpreter.virtualenv().platlib.as_path(),
-        ]
-        .iter()
-        .dedup()
-        .map(|path| {
-            pathdiff::diff_paths(path, &interpreter.virtualenv().scripts)
-                .expect("Failed to calculate relative path to site-packages")
-        })
-        .map(|path| path.simplified().to_str().unwrap().replace('\\', "\\\\"))
-        .join(path_sep);
+    Ok(())
+}
 
-        let virtual_env_dir = match (relocatable, name.to_owned()) {
-            (true, "activate") => {
-                r#"'"$(dirname -- "$(dirname -- "$(realpath -- "$SCRIPT_PATH")")")"'"#.to_string()
-            }
-            (true, "activate.bat") => r"%~dp0..".to_string(),
-            (true, "activate.fish") => {
-                r#"'"$(dirname -- "$(cd "$(dirname -- "$(status -f)")"; and pwd)")"'"#.to_string()
+/// Resolve the given executable into a macOS framework bundle, if it's reached through one of the
+/// framework's own links: a symlink from outside the framework into it (e.g.,
+/// `/usr/local/bin/python3`), or the framework's `Versions/Current` link.
+///
+/// Unlike full canonicalization, any other links are preserved, e.g., `python3` pointing to
+/// `python3.12` within the framework's `bin` directory, or links outside of any framework.
+fn resolve_framework_executable(executable: &Path) -> PathBuf {
+    // Follow a single symlink from outside a framework, if it leads into one.
+    let mut resolved = match fs::read_link(executable) {
+        Ok(target) if !is_framework_executable(executable) => {
+            let target = uv_fs::normalize_path(
+                &executable
+                    .parent()
+                    .map_or_else(|| target.clone(), |parent| parent.join(&target)),
+            );
+            if is_framework_executable(&target) {
+                target
+            } else {
+                executable.to_path_buf()
             }
-            // Note:
-            // * relocatable activate scripts appear not to be possible in csh and nu shell
-            // * `activate.ps1` is already relocatable by default.
-            _ => escape_posix_for_single_quotes(location.simplified().to_str().unwrap()),
-        };
+        }
+        _ => executable.to_path_buf(),
+    };
 
-        let activator = template
-            .replace("{{ VIRTUAL_ENV_DIR }}", &virtual_env_dir)
-            .replace("{{ BIN_NAME }}", bin_name)
-            .replace(
-                "{{ VIRTUAL_PROMPT }}",
-                prompt.as_deref().unwrap_or_default(),
-            )
-            .replace("{{ PATH_SEP }}", path_sep)
-            .replace("{{ RELATIVE_SITE_PACKAGES }}", &relative_site_packages);
-        fs::write(scripts.join(name), activator)?;
+    if !is_framework_executable(&resolved) {
+        return resolved;
     }
 
-    let mut pyvenv_cfg_data: Vec<(String, String)> = vec![
-        (
-            "home".to_string(),
-            python_home.simplified_display().to_string(),
-        ),
-        (
-            "implementation".to_string(),
-            interpreter
-                .markers()
-                .platform_python_implementation()
-                .to_string(),
-        ),
-        ("uv".to_string(), version().to_string()),
-        (
-            "version_info".to_string(),
-            interpreter.markers().python_full_version().string.clone(),
-        ),
-        (
-            "include-system-site-packages".to_string(),
-            if system_site_packages {
-                "true".to_string()
-            } else {
-                "false".to_string()
-            },
-        ),
-    ];
+    // Replace `Versions/Current` with the version it links to.
+    let components = resolved.components().collect::<Vec<_>>();
+    if let Some(index) = components.windows(2).position(|window| {
+        window[0].as_os_str() == "Versions" && window[1].as_os_str() == "Current"
+    }) {
+        let current = components[..=index + 1].iter().collect::<PathBuf>();
+        if let Ok(version) = fs::read_link(&current) {
+            let rest = components[index + 2..].iter().collect::<PathBuf>();
+            let versions = components[..=index].iter().collect::<PathBuf>();
+            resolved = uv_fs::normalize_path(&versions.join(version)).join(rest);
+        }
+    }
 
-    if relocatable {
-        pyvenv_cfg_data.push(("relocatable".to_string(), "true".to_string()));
+    resolved
+}
+
+/// Returns `true` if the given executable lives inside a macOS framework bundle, e.g., a
+/// `Python.framework/Versions/<version>/bin` directory.
+fn is_framework_executable(executable: &Path) -> bool {
+    executable.ancestors().any(|ancestor| {
+        ancestor
+            .extension()
+            .is_some_and(|extension| extension == "framework")
+    })
+}
+
+/// A directory alongside the environment's location, in which the environment is built before
+/// being moved into place.
+///
+/// Each staging directory is guarded by a sibling lock file with the same name and a `.lock`
+/// extension, which is created before the directory and held until both the directory and any
+/// environment it replaces are gone. A staging directory whose lock isn't held was abandoned by an
+/// interrupted creation.
+struct StagingDir {
+    path: PathBuf,
+    _lock: LockedFile,
+    _lock_path: tempfile::TempPath,
+}
+
+impl StagingDir {
+    /// Create a staging directory alongside the given location.
+    fn new(location: &Path) -> io::Result<Self> {
+        let parent = location.parent().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "The virtual environment location `{}` has no parent directory",
+                    location.user_display()
+                ),
+            )
+        })?;
+        fs::create_dir_all(parent)?;
+
+        remove_stale_staging_dirs(parent);
+
+        // Reserve a name by creating the lock file, and take the lock before creating the
+        // directory, such that the directory is never observed without a held lock.
+        let lock_path = tempfile::Builder::new()
+            .prefix(STAGING_PREFIX)
+            .suffix(".lock")
+            .tempfile_in(parent)?
+            .into_temp_path();
+        let path = lock_path.with_extension("");
+        let lock = LockedFile::acquire_blocking(&lock_path, path.user_display())?;
+
+        fs::create_dir(&path)?;
+
+        Ok(Self {
+            path,
+            _lock: lock,
+            _lock_path: lock_path,
+        })
     }
 
-    if seed {
-        pyvenv_cfg_data.push(("seed".to_string(), "true".to_string()));
+    fn path(&self) -> &Path {
+        &self.path
     }
+}
 
-    if let Some(prompt) = prompt {
-        pyvenv_cfg_data.push(("prompt".to_string(), prompt));
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        // Remove the staging directory, unless it was moved into place. The lock (and lock file)
+        // are released afterwards, as the fields are dropped.
+        match fs::remove_dir_all(&self.path) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => debug!(
+                "Failed to remove staging directory at `{}`: {err}",
+                self.path.user_display()
+            ),
+        }
     }
+}
 
-    if cfg!(windows) && interpreter.markers().implementation_name() == "graalpy" {
-        pyvenv_cfg_data.push((
-            "venvlauncher_command".to_string(),
-            python_home
-                .join("graalpy.exe")
-                .simplified_display()
-                .to_string(),
-        ));
+/// Remove any staging directories (and replaced environments) in the given directory that were
+/// left behind by an interrupted environment creation, e.g., due to a crash.
+///
+/// Staging directories whose lock is held belong to a creation that's still in progress, and are
+/// left alone.
+fn remove_stale_staging_dirs(parent: &Path) {
+    for directory in uv_fs::directories(parent) {
+        let Some(name) = directory.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !name.starts_with(STAGING_PREFIX) {
+            continue;
+        }
+
+        // A replaced environment is guarded by the lock of the staging directory that replaced it.
+        let staging = name.strip_suffix("-previous").unwrap_or(name);
+        let lock_path = parent.join(format!("{staging}.lock"));
+        let lock = match LockedFile::try_acquire(&lock_path, directory.user_display()) {
+            Ok(Some(lock)) => Some(lock),
+            Ok(None) => {
+                debug!(
+                    "Skipping staging directory in use: `{}`",
+                    directory.user_display()
+                );
+                continue;
+            }
+            // The lock file is removed last, so a staging directory without one was abandoned.
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => {
+                debug!("Failed to check staging directory: {err}");
+                continue;
+            }
+        };
+
+        debug!(
+            "Removing leftover staging directory: `{}`",
+            directory.user_display()
+        );
+        if let Err(err) = fs::remove_dir_all(&directory) {
+            debug!(
+                "Failed to remove leftover staging directory at `{}`: {err}",
+                directory.user_display()
+            );
+            continue;
+        }
+        if let Some(lock) = lock {
+            drop(lock);
+            if let Err(err) = fs::remove_file(&lock_path) {
+                debug!(
+                    "Failed to remove leftover lock file at `{}`: {err}",
+                    lock_path.user_display()
+                );
+            }
+        }
     }
+}
 
-    let mut pyvenv_cfg = BufWriter::new(File::create(location.join("pyvenv.cfg"))?);
-    write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
-    drop(pyvenv_cfg);
+/// Place the base Python executable at `target` using the given [`InterpreterLinkMode`].
+#[cfg(unix)]
+fn link_executable(
+    link_mode: InterpreterLinkMode,
+    base_python: &Path,
+    target: &Path,
+) -> io::Result<()> {
+    if link_mode == InterpreterLinkMode::Symlink {
+        return uv_fs::replace_symlink(base_python, target);
+    }
 
-    // Construct the path to the `site-packages` directory.
-    let site_packages = location.join(&interpreter.virtualenv().purelib);
-    fs::create_dir_all(&site_packages)?;
+    // Remove any existing entry first, so that we never write through a symlink left over from a
+    // previous environment into the base interpreter.
+    match fs::remove_file(target) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
 
-    // If necessary, create a symlink from `lib64` to `lib`.
-    // See: https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L135C11-L135C16
-    #[cfg(unix)]
-    if interpreter.pointer_size().is_64()
-        && interpreter.markers().os_name() == "posix"
-        && interpreter.markers().sys_platform() != "darwin"
-    {
-        match std::os::unix::fs::symlink("lib", location.join("lib64")) {
-            Ok(()) => {}
-            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+    if link_mode == InterpreterLinkMode::Hardlink {
+        match fs::hard_link(base_python, target) {
+            Ok(()) => return Ok(()),
             Err(err) => {
-                return Err(err.into());
+                debug!(
+                    "Failed to hard link `{}` to `{}`; falling back to copy: {err}",
+                    base_python.user_display(),
+                    target.user_display(),
+                );
             }
         }
     }
 
-    // Populate `site-packages` with a `_virtualenv.py` file.
-    fs::write(site_packages.join("_virtualenv.py"), VIRTUALENV_PATCH)?;
-    fs::write(site_packages.join("_virtualenv.pth"), "import _virtualenv")?;
-
-    Ok(VirtualEnvironment {
-        scheme: Scheme {
-            purelib: location.join(&interpreter.virtualenv().purelib),
-            platlib: location.join(&interpreter.virtualenv().platlib),
-            scripts: location.join(&interpreter.virtualenv().scripts),
-            data: location.join(&interpreter.virtualenv().data),
-            include: location.join(&interpreter.virtualenv().include),
-        },
-        root: location,
-        executable,
-    })
+    fs::copy(base_python, target)?;
+    Ok(())
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -608,3 +1091,361 @@ fn copy_launcher_windows(
 
     Err(Error::NotFound(base_python.user_display().to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use fs_err as fs;
+    use itertools::Itertools;
+
+    use uv_configuration::TargetTriple;
+    use uv_python::{ImplementationName, Interpreter, PyVenvConfiguration, PythonVersion};
+
+    use crate::{Error, InterpreterLinkMode, VenvOptions};
+
+    /// Create a stand-in for a CPython 3.12 installation at the given prefix.
+    fn interpreter(prefix: &Path) -> Interpreter {
+        cpython(prefix, "3.12", false)
+    }
+
+    /// Create a stand-in for a CPython installation of the given version at the given prefix.
+    fn cpython(prefix: &Path, version: &str, gil_disabled: bool) -> Interpreter {
+        fs::create_dir_all(prefix.join("bin")).unwrap();
+        fs::write(prefix.join("bin").join("python3"), "#!/bin/sh\n").unwrap();
+        Interpreter::artificial(
+            TargetTriple::Linux,
+            ImplementationName::CPython,
+            None,
+            &PythonVersion::from_str(version).unwrap(),
+            gil_disabled,
+            prefix.to_path_buf(),
+        )
+    }
+
+    fn create(location: &Path, interpreter: &Interpreter, link_mode: InterpreterLinkMode) {
+        super::create(
+            location,
+            interpreter,
+            VenvOptions {
+                link_mode,
+                activators: false,
+                ..VenvOptions::default()
+            },
+        )
+        .unwrap();
+    }
+
+    /// Returns the names of any staging directories (and their lock files) in the given directory.
+    fn staging_dirs(parent: &Path) -> Vec<String> {
+        fs::read_dir(parent)
+            .unwrap()
+            .filter_map(|entry| entry.unwrap().file_name().into_string().ok())
+            .filter(|name| name.starts_with(super::STAGING_PREFIX))
+            .sorted()
+            .collect()
+    }
+
+    /// When copying the base executable, it's copied once, and the aliases link to the copy.
+    #[test]
+    #[cfg(unix)]
+    fn copy_executable() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let interpreter = interpreter(&temp_dir.path().join("base"));
+
+        let location = temp_dir.path().join("venv");
+        create(&location, &interpreter, InterpreterLinkMode::Copy);
+
+        let bin = location.join("bin");
+        let python = fs::symlink_metadata(bin.join("python")).unwrap();
+        assert!(python.file_type().is_file());
+        assert_eq!(
+            fs::read_to_string(bin.join("python")).unwrap(),
+            "#!/bin/sh\n"
+        );
+        for alias in ["python3", "python3.12"] {
+            assert_eq!(
+                fs::read_link(bin.join(alias)).unwrap(),
+                PathBuf::from("python")
+            );
+        }
+    }
+
+    /// An existing environment is replaced, without leaving the old environment or the staging
+    /// directory behind.
+    #[test]
+    #[cfg(unix)]
+    fn replace_existing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let interpreter = interpreter(&temp_dir.path().join("base"));
+
+        let location = temp_dir.path().join("venv");
+        create(&location, &interpreter, InterpreterLinkMode::Symlink);
+        fs::write(location.join("stale"), "").unwrap();
+
+        create(&location, &interpreter, InterpreterLinkMode::Symlink);
+        assert!(location.join("pyvenv.cfg").is_file());
+        assert!(!location.join("stale").exists());
+        assert_eq!(staging_dirs(temp_dir.path()), Vec::<String>::new());
+    }
+
+    /// Staging directories left behind by an interrupted creation are removed.
+    #[test]
+    #[cfg(unix)]
+    fn remove_stale_staging_dirs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let interpreter = interpreter(&temp_dir.path().join("base"));
+
+        fs::create_dir_all(temp_dir.path().join(".tmp-venvAbC123").join("bin")).unwrap();
+        fs::create_dir_all(temp_dir.path().join(".tmp-venvAbC123-previous")).unwrap();
+
+        create(
+            &temp_dir.path().join("venv"),
+            &interpreter,
+            InterpreterLinkMode::Symlink,
+        );
+        assert_eq!(staging_dirs(temp_dir.path()), Vec::<String>::new());
+    }
+
+    /// A staging directory that's still in use by another creation in the same directory isn't
+    /// removed.
+    #[test]
+    #[cfg(unix)]
+    fn concurrent_creation() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let interpreter = interpreter(&temp_dir.path().join("base"));
+
+        // Start creating one environment, and create another alongside it in the meantime.
+        let first = super::StagingDir::new(&temp_dir.path().join("first")).unwrap();
+        fs::write(first.path().join("pyvenv.cfg"), "").unwrap();
+        let name = first
+            .path()
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        create(
+            &temp_dir.path().join("second"),
+            &interpreter,
+            InterpreterLinkMode::Symlink,
+        );
+        assert!(temp_dir.path().join("second").join("pyvenv.cfg").is_file());
+        assert!(first.path().join("pyvenv.cfg").is_file());
+        assert_eq!(
+            staging_dirs(temp_dir.path()),
+            vec![name.clone(), format!("{name}.lock")]
+        );
+
+        // Nothing is left behind once the first creation is finished.
+        drop(first);
+        assert_eq!(staging_dirs(temp_dir.path()), Vec::<String>::new());
+    }
+
+    /// Additional `pyvenv.cfg` entries are written after uv's own, but can't override them.
+    #[test]
+    #[cfg(unix)]
+    fn extra_cfg() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let interpreter = interpreter(&temp_dir.path().join("base"));
+        let location = temp_dir.path().join("venv");
+
+        for key in [
+            "home",
+            "HOME",
+            " Version_Info ",
+            "virtualenv",
+            "uv",
+            "a=b",
+            "",
+        ] {
+            let result = super::create(
+                &location,
+                &interpreter,
+                VenvOptions {
+                    extra_cfg: vec![(key.to_string(), "value".to_string())],
+                    ..VenvOptions::default()
+                },
+            );
+            assert!(
+                matches!(result, Err(Error::InvalidPyVenvCfgEntry(ref entry)) if entry == key),
+                "`{key}` should be rejected"
+            );
+        }
+        assert!(!location.exists());
+
+        super::create(
+            &location,
+            &interpreter,
+            VenvOptions {
+                extra_cfg: vec![
+                    ("custom".to_string(), "value".to_string()),
+                    ("another".to_string(), "other".to_string()),
+                ],
+                ..VenvOptions::default()
+            },
+        )
+        .unwrap();
+        let pyvenv_cfg = fs::read_to_string(location.join("pyvenv.cfg")).unwrap();
+        assert!(pyvenv_cfg.ends_with("custom = value\nanother = other\n"));
+
+        let cfg = PyVenvConfiguration::parse(location.join("pyvenv.cfg")).unwrap();
+        assert_eq!(cfg.get("custom"), Some("value"));
+        assert_eq!(cfg.get("another"), Some("other"));
+        let keys = cfg.entries().map(|(key, _)| key).collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            [
+                "home",
+                "implementation",
+                "uv",
+                "version_info",
+                "include-system-site-packages",
+                "custom",
+                "another",
+            ]
+        );
+    }
+
+    /// Re-linking points the environment at the new base interpreter, keeping its packages and
+    /// `pyvenv.cfg` entries.
+    #[test]
+    #[cfg(unix)]
+    fn relink() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let previous = interpreter(&temp_dir.path().join("previous"));
+        let requested = interpreter(&temp_dir.path().join("requested"));
+        let location = temp_dir.path().join("venv");
+
+        super::create(
+            &location,
+            &previous,
+            VenvOptions {
+                extra_cfg: vec![("custom".to_string(), "value".to_string())],
+                ..VenvOptions::default()
+            },
+        )
+        .unwrap();
+        let site_packages = location.join(&previous.virtualenv().purelib);
+        fs::write(site_packages.join("installed.py"), "").unwrap();
+
+        super::relink(&location, &requested, InterpreterLinkMode::Symlink).unwrap();
+
+        let home = temp_dir.path().join("requested").join("bin");
+        let cfg = PyVenvConfiguration::parse(location.join("pyvenv.cfg")).unwrap();
+        assert_eq!(cfg.get("home"), home.to_str());
+        assert_eq!(cfg.get("custom"), Some("value"));
+        assert_eq!(
+            fs::read_link(location.join("bin").join("python")).unwrap(),
+            home.join("python3")
+        );
+        assert!(site_packages.join("installed.py").is_file());
+    }
+
+    /// Re-linking to a different minor version or ABI is rejected, as the installed packages
+    /// wouldn't be usable.
+    #[test]
+    #[cfg(unix)]
+    fn relink_mismatch() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        for (previous, requested, message) in [
+            (
+                ("3.12", false),
+                ("3.13", false),
+                "CPython 3.12 to CPython 3.13",
+            ),
+            (
+                ("3.13", false),
+                ("3.13", true),
+                "CPython 3.13 to CPython 3.13t",
+            ),
+            (
+                ("3.13", true),
+                ("3.13", false),
+                "CPython 3.13t to CPython 3.13",
+            ),
+        ] {
+            let location = temp_dir.path().join("venv");
+            create(
+                &location,
+                &cpython(&temp_dir.path().join("previous"), previous.0, previous.1),
+                InterpreterLinkMode::Symlink,
+            );
+            let requested = cpython(&temp_dir.path().join("requested"), requested.0, requested.1);
+
+            let result = super::relink(&location, &requested, InterpreterLinkMode::Symlink);
+            let Err(err @ Error::RelinkVersionMismatch { .. }) = result else {
+                panic!("expected a version mismatch: {result:?}");
+            };
+            assert!(err.to_string().contains(message), "{err}");
+        }
+    }
+
+    /// Executables are resolved into a framework through its own links, but not canonicalized.
+    #[test]
+    #[cfg(unix)]
+    fn resolve_framework_executable() {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+
+        // Mirror the layout of the python.org installers.
+        let versions = root.join("Library/Frameworks/Python.framework/Versions");
+        let bin = versions.join("3.12").join("bin");
+        fs::create_dir_all(&bin).unwrap();
+        fs::write(bin.join("python3.12"), "").unwrap();
+        symlink("python3.12", bin.join("python3")).unwrap();
+        symlink("3.12", versions.join("Current")).unwrap();
+
+        let local_bin = root.join("usr/local/bin");
+        fs::create_dir_all(&local_bin).unwrap();
+        symlink(
+            "../../../Library/Frameworks/Python.framework/Versions/3.12/bin/python3",
+            local_bin.join("python3"),
+        )
+        .unwrap();
+        symlink(
+            versions.join("Current").join("bin").join("python3"),
+            local_bin.join("python3-current"),
+        )
+        .unwrap();
+
+        // A link from `/usr/local/bin` is resolved into the framework, but not further.
+        assert_eq!(
+            super::resolve_framework_executable(&local_bin.join("python3")),
+            bin.join("python3")
+        );
+
+        // `Versions/Current` is resolved, whether it's reached directly or through a link.
+        assert_eq!(
+            super::resolve_framework_executable(
+                &versions.join("Current").join("bin").join("python3")
+            ),
+            bin.join("python3")
+        );
+        assert_eq!(
+            super::resolve_framework_executable(&local_bin.join("python3-current")),
+            bin.join("python3")
+        );
+
+        // Executables within the framework are left as-is.
+        assert_eq!(
+            super::resolve_framework_executable(&bin.join("python3")),
+            bin.join("python3")
+        );
+
+        // As are links that don't lead into a framework.
+        let other = root.join("other");
+        fs::create_dir_all(&other).unwrap();
+        fs::write(other.join("python3"), "").unwrap();
+        symlink(other.join("python3"), root.join("python-other")).unwrap();
+        assert_eq!(
+            super::resolve_framework_executable(&root.join("python-other")),
+            root.join("python-other")
+        );
+    }
+}
@@ -138,11 +138,13 @@ async fn build_impl(
         keyring_provider,
         resolution: _,
         prerelease: _,
+        prerelease_package: _,
         dependency_metadata,
         config_setting,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package: _,
         link_mode,
         upgrade: _,
         build_options,
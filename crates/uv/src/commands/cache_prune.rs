@@ -9,8 +9,13 @@ use uv_fs::Simplified;
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
-/// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+/// Prune all unreachable objects from the cache, then evict unzipped wheels beyond `max_size`.
+pub(crate) fn cache_prune(
+    ci: bool,
+    max_size: Option<u64>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -26,6 +31,10 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
         cache.root().user_display().cyan()
     )?;
 
+    let _lock = cache
+        .lock()
+        .with_context(|| format!("Failed to lock cache at: {}", cache.root().user_display()))?;
+
     let mut summary = Removal::default();
 
     // Prune the source distribution cache, which is tightly coupled to the builder crate.
@@ -37,6 +46,13 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    // Evict the least-recently-used archives, if the cache exceeds its budget.
+    if let Some(max_size) = max_size {
+        summary += cache.prune_to_size(max_size).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
+    }
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -104,11 +104,10 @@ impl CachedEnvironment {
         let venv = uv_virtualenv::create_venv(
             temp_dir.path(),
             interpreter,
-            uv_virtualenv::Prompt::None,
-            false,
-            false,
-            true,
-            false,
+            uv_virtualenv::VenvOptions {
+                relocatable: true,
+                ..uv_virtualenv::VenvOptions::default()
+            },
         )?;
 
         sync_environment(
@@ -130,7 +129,7 @@ impl CachedEnvironment {
         let id = cache
             .persist(temp_dir.into_path(), cache_entry.path())
             .await?;
-        let root = cache.archive(&id);
+        let root = cache.access_archive(&id);
 
         Ok(Self(PythonEnvironment::from_root(root, cache)?))
     }
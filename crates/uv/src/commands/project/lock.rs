@@ -1,11 +1,11 @@
 #![allow(clippy::single_match_else)]
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::path::Path;
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
-use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::Cache;
@@ -27,8 +27,9 @@ use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreferenc
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::ExtrasResolver;
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, LockVersion, Options, OptionsBuilder, PythonRequirement,
-    RequiresPython, ResolverEnvironment, ResolverManifest, SatisfiesResult, VERSION,
+    ExcludeNewer, FlatIndex, InMemoryIndex, Lock, LockVersion, Options, OptionsBuilder,
+    PackageDiff, PythonRequirement, RequiresPython, ResolverEnvironment, ResolverManifest,
+    SatisfiesResult, VERSION,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
@@ -309,11 +310,13 @@ async fn do_lock(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_package,
         dependency_metadata,
         config_setting,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         upgrade,
         build_options,
@@ -456,7 +459,13 @@ async fn do_lock(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
+        .prerelease_packages(prerelease_package.iter().cloned())
         .exclude_newer(exclude_newer)
+        .exclude_newer_packages(
+            exclude_newer_package
+                .iter()
+                .map(|(package_name, exclude_newer)| (package_name.clone(), *exclude_newer)),
+        )
         .index_strategy(index_strategy)
         .build();
     let hasher = HashStrategy::Generate;
@@ -728,6 +737,15 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        if *lock.prerelease_packages() != options.prerelease_packages {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in pre-release packages: `{}` vs. `{}`",
+                lock.prerelease_packages().iter().join(", ").cyan(),
+                options.prerelease_packages.iter().join(", ").cyan()
+            );
+            return Ok(Self::Unusable(lock));
+        }
         match (lock.exclude_newer(), options.exclude_newer) {
             (None, None) => (),
             (Some(existing), Some(provided)) if existing == provided => (),
@@ -757,6 +775,21 @@ impl ValidatedLock {
                 return Ok(Self::Unusable(lock));
             }
         }
+        if *lock.exclude_newer_packages() != options.exclude_newer_packages {
+            let format = |exclude_newer_packages: &BTreeMap<PackageName, ExcludeNewer>| {
+                exclude_newer_packages
+                    .iter()
+                    .map(|(package_name, exclude_newer)| format!("{package_name}={exclude_newer}"))
+                    .join(", ")
+            };
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in per-package timestamp cutoffs: `{}` vs. `{}`",
+                format(lock.exclude_newer_packages()).cyan(),
+                format(&options.exclude_newer_packages).cyan()
+            );
+            return Ok(Self::Unusable(lock));
+        }
 
         match upgrade {
             Upgrade::None => {}
@@ -1018,88 +1051,58 @@ fn report_upgrades(
     printer: Printer,
     dry_run: bool,
 ) -> anyhow::Result<bool> {
-    let existing_packages: FxHashMap<&PackageName, BTreeSet<&Version>> =
-        if let Some(existing_lock) = existing_lock {
-            existing_lock.packages().iter().fold(
-                FxHashMap::with_capacity_and_hasher(existing_lock.packages().len(), FxBuildHasher),
-                |mut acc, package| {
-                    acc.entry(package.name())
-                        .or_default()
-                        .insert(package.version());
-                    acc
-                },
-            )
-        } else {
-            FxHashMap::default()
-        };
-
-    let new_distributions: FxHashMap<&PackageName, BTreeSet<&Version>> =
-        new_lock.packages().iter().fold(
-            FxHashMap::with_capacity_and_hasher(new_lock.packages().len(), FxBuildHasher),
-            |mut acc, package| {
-                acc.entry(package.name())
-                    .or_default()
-                    .insert(package.version());
-                acc
-            },
-        );
+    fn format_versions(versions: &BTreeSet<Version>) -> String {
+        versions
+            .iter()
+            .map(|version| format!("v{version}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 
-    let mut updated = false;
-    for name in existing_packages
-        .keys()
-        .chain(new_distributions.keys())
-        .collect::<BTreeSet<_>>()
-    {
-        updated = true;
-        match (existing_packages.get(name), new_distributions.get(name)) {
-            (Some(existing_versions), Some(new_versions)) => {
-                if existing_versions != new_versions {
-                    let existing_versions = existing_versions
-                        .iter()
-                        .map(|version| format!("v{version}"))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    let new_versions = new_versions
-                        .iter()
-                        .map(|version| format!("v{version}"))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    writeln!(
-                        printer.stderr(),
-                        "{} {name} {existing_versions} -> {new_versions}",
-                        if dry_run { "Update" } else { "Updated" }.green().bold()
-                    )?;
-                }
-            }
-            (Some(existing_versions), None) => {
-                let existing_versions = existing_versions
-                    .iter()
-                    .map(|version| format!("v{version}"))
-                    .collect::<Vec<_>>()
-                    .join(", ");
+    let existing_packages = existing_lock
+        .map(Lock::packages)
+        .unwrap_or_default()
+        .iter()
+        .map(|package| (package.name(), package.version()));
+    let new_packages = new_lock
+        .packages()
+        .iter()
+        .map(|package| (package.name(), package.version()));
+
+    for change in PackageDiff::compute(existing_packages, new_packages) {
+        match change {
+            PackageDiff::Upgraded { name, from, to }
+            | PackageDiff::Downgraded { name, from, to }
+            | PackageDiff::Changed { name, from, to } => {
                 writeln!(
                     printer.stderr(),
-                    "{} {name} {existing_versions}",
-                    if dry_run { "Remove" } else { "Removed" }.red().bold()
+                    "{} {name} {} -> {}",
+                    if dry_run { "Update" } else { "Updated" }.green().bold(),
+                    format_versions(&from),
+                    format_versions(&to)
                 )?;
             }
-            (None, Some(new_versions)) => {
-                let new_versions = new_versions
-                    .iter()
-                    .map(|version| format!("v{version}"))
-                    .collect::<Vec<_>>()
-                    .join(", ");
+            PackageDiff::Removed { name, versions } => {
                 writeln!(
                     printer.stderr(),
-                    "{} {name} {new_versions}",
-                    if dry_run { "Add" } else { "Added" }.green().bold()
+                    "{} {name} {}",
+                    if dry_run { "Remove" } else { "Removed" }.red().bold(),
+                    format_versions(&versions)
                 )?;
             }
-            (None, None) => {
-                unreachable!("The key `{name}` should exist in at least one of the maps");
+            PackageDiff::Added { name, versions } => {
+                writeln!(
+                    printer.stderr(),
+                    "{} {name} {}",
+                    if dry_run { "Add" } else { "Added" }.green().bold(),
+                    format_versions(&versions)
+                )?;
             }
         }
     }
 
+    // Any change to a lockfile with packages counts as an update, even if no versions changed.
+    let updated = existing_lock.is_some_and(|lock| !lock.packages().is_empty())
+        || !new_lock.packages().is_empty();
     Ok(updated)
 }
@@ -619,6 +619,12 @@ impl ProjectInterpreter {
                             ));
                         }
                     }
+                    InvalidEnvironmentKind::MissingBaseInterpreter(ref home) => {
+                        warn_user!(
+                            "Ignoring existing virtual environment linked to non-existent Python installation: {}",
+                            home.user_display().cyan(),
+                        );
+                    }
                 };
             }
             Err(uv_python::Error::Query(uv_python::InterpreterError::NotFound(path))) => {
@@ -794,11 +800,10 @@ pub(crate) async fn get_or_init_environment(
             Ok(uv_virtualenv::create_venv(
                 &venv,
                 interpreter,
-                prompt,
-                false,
-                false,
-                false,
-                false,
+                uv_virtualenv::VenvOptions {
+                    prompt,
+                    ..uv_virtualenv::VenvOptions::default()
+                },
             )?)
         }
     }
@@ -840,11 +845,13 @@ pub(crate) async fn resolve_names(
         keyring_provider,
         resolution: _,
         prerelease: _,
+        prerelease_package: _,
         dependency_metadata,
         config_setting,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package: _,
         link_mode,
         compile_bytecode: _,
         sources,
@@ -977,11 +984,13 @@ pub(crate) async fn resolve_environment<'a>(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_package,
         dependency_metadata,
         config_setting,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         upgrade: _,
         build_options,
@@ -1037,7 +1046,13 @@ pub(crate) async fn resolve_environment<'a>(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution)
         .prerelease_mode(prerelease)
+        .prerelease_packages(prerelease_package.iter().cloned())
         .exclude_newer(exclude_newer)
+        .exclude_newer_packages(
+            exclude_newer_package
+                .iter()
+                .map(|(package_name, exclude_newer)| (package_name.clone(), *exclude_newer)),
+        )
         .index_strategy(index_strategy)
         .build();
 
@@ -1306,11 +1321,13 @@ pub(crate) async fn update_environment(
         keyring_provider,
         resolution,
         prerelease,
+        prerelease_package,
         dependency_metadata,
         config_setting,
         no_build_isolation,
         no_build_isolation_package,
         exclude_newer,
+        exclude_newer_package,
         link_mode,
         compile_bytecode,
         sources,
@@ -1391,7 +1408,13 @@ pub(crate) async fn update_environment(
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
+        .prerelease_packages(prerelease_package.iter().cloned())
         .exclude_newer(*exclude_newer)
+        .exclude_newer_packages(
+            exclude_newer_package
+                .iter()
+                .map(|(package_name, exclude_newer)| (package_name.clone(), *exclude_newer)),
+        )
         .index_strategy(*index_strategy)
         .build();
 
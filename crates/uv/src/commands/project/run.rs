@@ -358,11 +358,7 @@ pub(crate) async fn run(
             let environment = uv_virtualenv::create_venv(
                 temp_dir.path(),
                 interpreter,
-                uv_virtualenv::Prompt::None,
-                false,
-                false,
-                false,
-                false,
+                uv_virtualenv::VenvOptions::default(),
             )?;
 
             Some(environment.into_interpreter())
@@ -575,11 +571,7 @@ pub(crate) async fn run(
                 uv_virtualenv::create_venv(
                     temp_dir.path(),
                     interpreter,
-                    uv_virtualenv::Prompt::None,
-                    false,
-                    false,
-                    false,
-                    false,
+                    uv_virtualenv::VenvOptions::default(),
                 )?
             } else {
                 // If we're not isolating the environment, reuse the base environment for the
@@ -800,11 +792,7 @@ pub(crate) async fn run(
                 let venv = uv_virtualenv::create_venv(
                     temp_dir.path(),
                     interpreter,
-                    uv_virtualenv::Prompt::None,
-                    false,
-                    false,
-                    false,
-                    false,
+                    uv_virtualenv::VenvOptions::default(),
                 )?;
                 venv.into_interpreter()
             } else {
@@ -850,11 +838,7 @@ pub(crate) async fn run(
                 uv_virtualenv::create_venv(
                     temp_dir.path(),
                     base_interpreter.clone(),
-                    uv_virtualenv::Prompt::None,
-                    false,
-                    false,
-                    false,
-                    false,
+                    uv_virtualenv::VenvOptions::default(),
                 )?
             }
             Some(spec) => {
@@ -149,11 +149,13 @@ pub(crate) async fn tree(
             keyring_provider,
             resolution: _,
             prerelease: _,
+            prerelease_package: _,
             dependency_metadata: _,
             config_setting: _,
             no_build_isolation: _,
             no_build_isolation_package: _,
             exclude_newer: _,
+            exclude_newer_package: _,
             link_mode: _,
             upgrade: _,
             build_options: _,
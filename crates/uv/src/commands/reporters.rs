@@ -8,9 +8,10 @@ use rustc_hash::FxHashMap;
 use url::Url;
 use uv_cache::Removal;
 use uv_distribution_types::{
-    BuildableSource, CachedDist, DistributionMetadata, Name, SourceDist, VersionOrUrlRef,
+    BuildableSource, CachedDist, Dist, DistributionMetadata, Name, SourceDist, VersionOrUrlRef,
 };
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_python::PythonInstallationKey;
 use uv_static::EnvVars;
 
@@ -345,11 +346,23 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
         }
     }
 
+    fn on_try_version(&self, name: &PackageName, version: &Version) {
+        self.reporter.root.set_message(format!("{name}=={version}"));
+    }
+
     fn on_complete(&self) {
         self.reporter.root.set_message("");
         self.reporter.root.finish_and_clear();
     }
 
+    fn on_fetch_versions(&self, name: &PackageName) {
+        self.reporter.root.set_message(format!("{name}"));
+    }
+
+    fn on_fetch_metadata(&self, dist: &Dist) {
+        self.reporter.root.set_message(format!("{dist}"));
+    }
+
     fn on_build_start(&self, source: &BuildableSource) -> usize {
         self.reporter.on_build_start(source)
     }
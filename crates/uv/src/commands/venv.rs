@@ -65,6 +65,7 @@ pub(crate) async fn venv(
     cache: &Cache,
     printer: Printer,
     relocatable: bool,
+    no_activators: bool,
 ) -> Result<ExitStatus> {
     match venv_impl(
         project_dir,
@@ -92,6 +93,7 @@ pub(crate) async fn venv(
         cache,
         printer,
         relocatable,
+        no_activators,
     )
     .await
     {
@@ -150,6 +152,7 @@ async fn venv_impl(
     cache: &Cache,
     printer: Printer,
     relocatable: bool,
+    no_activators: bool,
 ) -> miette::Result<ExitStatus> {
     let project = if no_project {
         None
@@ -272,11 +275,16 @@ async fn venv_impl(
     let venv = uv_virtualenv::create_venv(
         &path,
         interpreter,
-        prompt,
-        system_site_packages,
-        allow_existing,
-        relocatable,
-        seed,
+        uv_virtualenv::VenvOptions {
+            prompt,
+            system_site_packages,
+            allow_existing,
+            relocatable,
+            seed,
+            link_mode: uv_virtualenv::InterpreterLinkMode::default(),
+            activators: !no_activators,
+            extra_cfg: Vec::new(),
+        },
     )
     .map_err(VenvError::Creation)?;
 
@@ -390,8 +398,8 @@ async fn venv_impl(
             .into_diagnostic()?;
     }
 
-    // Determine the appropriate activation command.
-    let activation = match Shell::from_env() {
+    // Determine the appropriate activation command, if activation scripts were written.
+    let activation = match Shell::from_env().filter(|_| !no_activators) {
         None => None,
         Some(Shell::Bash | Shell::Zsh | Shell::Ksh) => Some(format!(
             "source {}",
@@ -409,10 +417,20 @@ async fn venv_impl(
             "source {}",
             shlex_posix(venv.scripts().join("activate.csh"))
         )),
-        Some(Shell::Powershell) => Some(shlex_windows(
+        Some(Shell::Xonsh) => Some(format!(
+            "source {}",
+            shlex_posix(venv.scripts().join("activate.xsh"))
+        )),
+        // On Windows, PowerShell resolves `activate` to `activate.ps1`; on Unix, it would pick up
+        // the POSIX `activate` script instead.
+        Some(Shell::Powershell) if cfg!(windows) => Some(shlex_windows(
             venv.scripts().join("activate"),
             Shell::Powershell,
         )),
+        Some(Shell::Powershell) => Some(shlex_windows(
+            venv.scripts().join("activate.ps1"),
+            Shell::Powershell,
+        )),
         Some(Shell::Cmd) => Some(shlex_windows(venv.scripts().join("activate"), Shell::Cmd)),
     };
     if let Some(act) = activation {
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::env::VarError;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
@@ -2024,6 +2025,7 @@ pub(crate) struct VenvSettings {
     pub(crate) prompt: Option<String>,
     pub(crate) system_site_packages: bool,
     pub(crate) relocatable: bool,
+    pub(crate) no_activators: bool,
     pub(crate) no_project: bool,
     pub(crate) settings: PipSettings,
 }
@@ -2041,6 +2043,7 @@ impl VenvSettings {
             prompt,
             system_site_packages,
             relocatable,
+            no_activators,
             index_args,
             index_strategy,
             keyring_provider,
@@ -2058,6 +2061,7 @@ impl VenvSettings {
             system_site_packages,
             no_project,
             relocatable,
+            no_activators,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2107,11 +2111,13 @@ pub(crate) struct ResolverSettings {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: Vec<PackageName>,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: BTreeMap<PackageName, ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
     pub(crate) upgrade: Upgrade,
     pub(crate) build_options: BuildOptions,
@@ -2125,11 +2131,13 @@ pub(crate) struct ResolverSettingsRef<'a> {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: &'a [PackageName],
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: &'a BTreeMap<PackageName, ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
     pub(crate) upgrade: &'a Upgrade,
     pub(crate) build_options: &'a BuildOptions,
@@ -2156,11 +2164,13 @@ impl ResolverSettings {
             keyring_provider: self.keyring_provider,
             resolution: self.resolution,
             prerelease: self.prerelease,
+            prerelease_package: &self.prerelease_package,
             dependency_metadata: &self.dependency_metadata,
             config_setting: &self.config_setting,
             no_build_isolation: self.no_build_isolation,
             no_build_isolation_package: &self.no_build_isolation_package,
             exclude_newer: self.exclude_newer,
+            exclude_newer_package: &self.exclude_newer_package,
             link_mode: self.link_mode,
             upgrade: &self.upgrade,
             build_options: &self.build_options,
@@ -2190,6 +2200,7 @@ impl From<ResolverOptions> for ResolverSettings {
             ),
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            prerelease_package: value.prerelease_package.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -2199,6 +2210,7 @@ impl From<ResolverOptions> for ResolverSettings {
             no_build_isolation: value.no_build_isolation.unwrap_or_default(),
             no_build_isolation_package: value.no_build_isolation_package.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package.unwrap_or_default(),
             link_mode: value.link_mode.unwrap_or_default(),
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
             upgrade: Upgrade::from_args(
@@ -2225,11 +2237,13 @@ pub(crate) struct ResolverInstallerSettingsRef<'a> {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: &'a [PackageName],
     pub(crate) dependency_metadata: &'a DependencyMetadata,
     pub(crate) config_setting: &'a ConfigSettings,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: &'a [PackageName],
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: &'a BTreeMap<PackageName, ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) sources: SourceStrategy,
@@ -2251,11 +2265,13 @@ pub(crate) struct ResolverInstallerSettings {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: Vec<PackageName>,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) no_build_isolation: bool,
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
+    pub(crate) exclude_newer_package: BTreeMap<PackageName, ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) sources: SourceStrategy,
@@ -2287,11 +2303,13 @@ impl ResolverInstallerSettings {
             keyring_provider: self.keyring_provider,
             resolution: self.resolution,
             prerelease: self.prerelease,
+            prerelease_package: &self.prerelease_package,
             dependency_metadata: &self.dependency_metadata,
             config_setting: &self.config_setting,
             no_build_isolation: self.no_build_isolation,
             no_build_isolation_package: &self.no_build_isolation_package,
             exclude_newer: self.exclude_newer,
+            exclude_newer_package: &self.exclude_newer_package,
             link_mode: self.link_mode,
             compile_bytecode: self.compile_bytecode,
             sources: self.sources,
@@ -2323,6 +2341,7 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
             ),
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            prerelease_package: value.prerelease_package.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -2332,6 +2351,7 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
             no_build_isolation: value.no_build_isolation.unwrap_or_default(),
             no_build_isolation_package: value.no_build_isolation_package.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
+            exclude_newer_package: value.exclude_newer_package.unwrap_or_default(),
             link_mode: value.link_mode.unwrap_or_default(),
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
@@ -2487,11 +2507,15 @@ impl PipSettings {
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
+            // Per-package pre-release settings aren't supported by the `uv pip` interface.
+            prerelease_package: _,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
             no_build_isolation: top_level_no_build_isolation,
             no_build_isolation_package: top_level_no_build_isolation_package,
             exclude_newer: top_level_exclude_newer,
+            // Per-package exclusion dates aren't supported by the `uv pip` interface.
+            exclude_newer_package: _,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
             no_sources: top_level_no_sources,
@@ -2703,11 +2727,13 @@ impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
             keyring_provider: settings.keyring_provider,
             resolution: settings.resolution,
             prerelease: settings.prerelease,
+            prerelease_package: settings.prerelease_package,
             dependency_metadata: settings.dependency_metadata,
             config_setting: settings.config_setting,
             no_build_isolation: settings.no_build_isolation,
             no_build_isolation_package: settings.no_build_isolation_package,
             exclude_newer: settings.exclude_newer,
+            exclude_newer_package: settings.exclude_newer_package,
             link_mode: settings.link_mode,
             upgrade: settings.upgrade,
             build_options: settings.build_options,
@@ -1400,6 +1400,8 @@ fn build_constraints() -> Result<()> {
     error: Failed to resolve requirements from `build-system.requires`
       Caused by: No solution found when resolving: `setuptools>=42`
       Caused by: Because you require setuptools>=42 and setuptools==0.1.0, we can conclude that your requirements are unsatisfiable.
+
+    hint: The constraint `setuptools==0.1.0` was declared in `constraints.txt`
     "###);
 
     project
@@ -97,6 +97,7 @@ fn clean_package_pypi() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/`
     No unused entries found
     "###);
 
@@ -37,6 +37,7 @@ fn prune_no_op() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/`
     No unused entries found
     "###);
 
@@ -76,6 +77,7 @@ fn prune_stale_directory() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/`
     DEBUG Removing dangling cache bucket: [CACHE_DIR]/simple-v4
     Removed 1 directory
     "###);
@@ -83,6 +85,66 @@ fn prune_stale_directory() -> Result<()> {
     Ok(())
 }
 
+/// `cache prune --max-size` should evict unzipped wheels until they fit within the budget.
+#[test]
+fn prune_max_size() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = std::iter::once((r"Removed \d+ files", "Removed [N] files"))
+        .chain(context.filters())
+        .collect();
+
+    // The unzipped wheel fits within a generous budget.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("1GiB"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // But not within an empty one.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    let archives = fs_err::read_dir(context.cache_dir.path().join("archive-v0"))?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .filter(|entry| entry.file_name() != ".accessed")
+        .count();
+    assert_eq!(archives, 0);
+
+    // Reinstalling the package should require a download, since we evicted the wheel.
+    context.venv().assert().success();
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--offline")
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
 /// `cache prune` should remove all cached environments from the cache.
 #[test]
 fn prune_cached_env() {
@@ -136,6 +198,7 @@ fn prune_cached_env() {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/`
     DEBUG Removing dangling cache environment: [CACHE_DIR]/environments-v1/[ENTRY]
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
@@ -181,6 +244,7 @@ fn prune_stale_symlink() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/`
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed 44 files ([SIZE])
     "###);
@@ -355,6 +419,7 @@ fn prune_stale_revision() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/`
     DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v6/[ENTRY]
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
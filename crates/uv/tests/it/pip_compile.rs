@@ -2447,6 +2447,10 @@ fn requirement_constraint_override_url() -> Result<()> {
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because there is no version of anyio==3.7.0 and you require anyio==3.7.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl` was declared in `constraints.txt`
+
+          hint: The override `anyio==3.7.0` was declared in `overrides.txt`
     "###
     );
 
@@ -6278,6 +6282,8 @@ fn compile_constraints_incompatible_url() -> Result<()> {
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because only anyio>=4 is available and you require anyio<4, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl` was declared in `constraints.txt`
     "###
     );
 
@@ -9013,6 +9019,8 @@ fn compile_constraints_incompatible_version() -> Result<()> {
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because you require filelock==1.0.0 and filelock==3.8.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `filelock==3.8.0` was declared in `constraints.txt`
     "###
     );
 
@@ -9041,6 +9049,8 @@ fn conflicting_url_markers() -> Result<()> {
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because you require filelock==1.0.0 and filelock==3.8.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `filelock==3.8.0` was declared in `constraints.txt`
     "###
     );
 
@@ -9195,6 +9205,10 @@ fn override_with_incompatible_constraint() -> Result<()> {
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because you require anyio>=3.0.0 and anyio<3.0.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `anyio<3.0.0` was declared in `constraints.txt`
+
+          hint: The override `anyio>=3.0.0` was declared in `overrides.txt`
     "###
     );
 
@@ -9502,6 +9516,64 @@ dev = [
     Ok(())
 }
 
+/// Resolve from a `pyproject.toml` file with an extra that aggregates other, self-referential
+/// extras (e.g., `project[all]` enabling `project[test]` and `project[dev]`).
+#[test]
+fn compile_pyproject_toml_aggregate_recursive_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+[project]
+name = "project"
+version = "0.0.1"
+dependencies = [
+    "anyio"
+]
+
+[project.optional-dependencies]
+test = [
+    "iniconfig",
+]
+dev = [
+    "typing-extensions",
+    "project[test]",
+]
+all = [
+    "project[test]",
+    "project[dev]",
+]
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("all"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --extra all
+    anyio==4.3.0
+        # via project (pyproject.toml)
+    idna==3.6
+        # via anyio
+    iniconfig==2.0.0
+        # via project (pyproject.toml)
+    sniffio==1.3.1
+        # via anyio
+    typing-extensions==4.10.0
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// The dependencies of a local editable dependency should be considered "direct" dependencies.
 #[test]
 fn editable_direct_dependency() -> Result<()> {
@@ -12416,6 +12488,8 @@ fn incompatible_build_constraint() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `setuptools==1` was declared in `build_constraints.txt`
     "###
     );
 
@@ -1155,6 +1155,8 @@ fn install_editable_incompatible_constraint_version() -> Result<()> {
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because only black<=0.1.0 is available and you require black>0.1.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `black>0.1.0` was declared in `constraints.txt`
     "###
     );
 
@@ -7003,6 +7005,8 @@ fn incompatible_build_constraint() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `setuptools==1` was declared in `build_constraints.txt`
     "###
     );
 
@@ -2983,6 +2983,8 @@ fn incompatible_constraint() -> Result<()> {
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because you require anyio==3.7.0 and anyio==3.6.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `anyio==3.6.0` was declared in `constraints.txt`
     "###
     );
 
@@ -5476,6 +5478,8 @@ fn incompatible_build_constraint() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+          hint: The constraint `setuptools==1` was declared in `build_constraints.txt`
     "###
     );
 
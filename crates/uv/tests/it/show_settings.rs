@@ -2725,6 +2725,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                 LowestDirect,
             ),
             prerelease: None,
+            prerelease_package: None,
             dependency_metadata: None,
             config_settings: None,
             no_build_isolation: None,
@@ -2734,6 +2735,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            exclude_newer_package: None,
             link_mode: Some(
                 Clone,
             ),
@@ -2758,6 +2760,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: [],
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2771,6 +2774,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                     2024-03-25T00:00:00Z,
                 ),
             ),
+            exclude_newer_package: {},
             link_mode: Clone,
             compile_bytecode: false,
             sources: Enabled,
@@ -3813,6 +3813,60 @@ fn sync_invalid_environment() -> Result<()> {
     Ok(())
 }
 
+/// A virtual environment whose base interpreter was removed should be detected and recreated.
+#[test]
+fn sync_missing_base_interpreter() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.sync().assert().success();
+
+    // Point `home` at a base interpreter that no longer exists.
+    let pyvenv_cfg = context.temp_dir.join(".venv").join("pyvenv.cfg");
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?
+        .lines()
+        .map(|line| {
+            if line.starts_with("home") {
+                format!(
+                    "home = {}",
+                    context.temp_dir.join("missing-python").display()
+                )
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    fs_err::write(&pyvenv_cfg, contents)?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring existing virtual environment linked to non-existent Python installation: missing-python
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
 /// Avoid validating workspace members when `--no-sources` is provided. Rather than reporting that
 /// `./anyio` is missing, install `anyio` from the registry.
 #[test]
@@ -6,7 +6,7 @@ use predicates::prelude::*;
 use uv_python::{PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME};
 use uv_static::EnvVars;
 
-use crate::common::{uv_snapshot, TestContext};
+use crate::common::{uv_snapshot, venv_bin_path, TestContext};
 
 #[test]
 fn create_venv() {
@@ -1003,6 +1003,12 @@ fn verify_pyvenv_cfg_relocatable() {
     let activate_fish = scripts.child("activate.fish");
     activate_fish.assert(predicates::path::is_file());
     activate_fish.assert(predicates::str::contains(r#"set -gx VIRTUAL_ENV ''"$(dirname -- "$(cd "$(dirname -- "$(status -f)")"; and pwd)")"''"#));
+
+    let activate_xsh = scripts.child("activate.xsh");
+    activate_xsh.assert(predicates::path::is_file());
+    activate_xsh.assert(predicates::str::contains(
+        "$VIRTUAL_ENV = _os_path.dirname(_os_path.dirname(_os_path.realpath(__file__)))",
+    ));
 }
 
 /// Ensure that a nested virtual environment uses the same `home` directory as the parent.
@@ -1139,3 +1145,116 @@ fn create_venv_apostrophe() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert_eq!(stdout.trim(), venv_dir.to_string_lossy());
 }
+
+#[test]
+fn create_venv_no_activators() {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .arg("--no-activators"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    "###
+    );
+
+    // The environment is usable, but none of the activation scripts are written.
+    let bin = venv_bin_path(&context.venv);
+    assert!(bin
+        .join(format!("python{}", std::env::consts::EXE_SUFFIX))
+        .exists());
+    for activator in [
+        "activate",
+        "activate.bat",
+        "activate.csh",
+        "activate.fish",
+        "activate.nu",
+        "activate.ps1",
+        "activate.xsh",
+        "activate_this.py",
+    ] {
+        assert!(
+            !bin.join(activator).exists(),
+            "{activator} should not exist"
+        );
+    }
+}
+
+/// Remove the environment variables used to detect the current shell.
+fn without_shell(command: &mut std::process::Command) -> &mut std::process::Command {
+    command
+        .env_remove(EnvVars::NU_VERSION)
+        .env_remove(EnvVars::XONSH_VERSION)
+        .env_remove(EnvVars::FISH_VERSION)
+        .env_remove(EnvVars::BASH_VERSION)
+        .env_remove(EnvVars::ZSH_VERSION)
+        .env_remove(EnvVars::KSH_VERSION)
+        .env_remove(EnvVars::SHELL)
+}
+
+#[test]
+fn create_venv_xonsh() {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    uv_snapshot!(context.filters(), without_shell(&mut context.venv())
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .env(EnvVars::XONSH_VERSION, "0.19.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate.xsh
+    "###
+    );
+
+    // The environment path is written as a Python string literal.
+    let activate_xsh = venv_bin_path(&context.venv).join("activate.xsh");
+    let contents = fs_err::read_to_string(activate_xsh).unwrap();
+    assert!(contents
+        .lines()
+        .any(|line| line.starts_with("$VIRTUAL_ENV = \"") && line.ends_with(".venv\"")));
+}
+
+/// On Unix, PowerShell would resolve `activate` to the POSIX script, so the hint names
+/// `activate.ps1`, which uses the Unix `bin` directory and `PATH` separator.
+#[test]
+#[cfg(unix)]
+fn create_venv_pwsh_unix() {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    uv_snapshot!(context.filters(), without_shell(&mut context.venv())
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .env(EnvVars::SHELL, "/usr/bin/pwsh"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: .venv/[BIN]/activate.ps1
+    "###
+    );
+
+    context
+        .venv
+        .child("bin")
+        .child("activate.ps1")
+        .assert(predicates::str::contains(
+            r#"$env:PATH = "$env:VIRTUAL_ENV/bin:" + $env:PATH"#,
+        ));
+}